@@ -0,0 +1,71 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn test_name(day: &str, fixture: &str) -> String {
+    let fixture: String = fixture
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_lowercase(),
+            false => '_',
+        })
+        .collect();
+
+    format!("{day}_{fixture}")
+}
+
+fn find_fixtures(source_dir: &Path) -> Vec<(String, u8, PathBuf, PathBuf)> {
+    let mut fixtures = vec![];
+    let Ok(entries) = fs::read_dir(source_dir) else {
+        return fixtures;
+    };
+
+    for entry in entries.flatten() {
+        let day_name = entry.file_name().to_string_lossy().to_string();
+        let Some(day) = day_name
+            .strip_prefix("day_")
+            .and_then(|day| day.parse::<u8>().ok())
+        else {
+            continue;
+        };
+        let Ok(examples) = fs::read_dir(entry.path().join("examples")) else {
+            continue;
+        };
+
+        for example in examples.flatten() {
+            let input = example.path();
+            let expected = input.with_extension("expected");
+
+            if input
+                .extension()
+                .is_some_and(|extension| extension == "txt")
+                && expected.exists()
+            {
+                let name = input.file_stem().unwrap().to_string_lossy().to_string();
+                fixtures.push((test_name(&day_name, &name), day, input, expected));
+            }
+        }
+    }
+
+    fixtures.sort();
+    fixtures
+}
+
+fn main() {
+    let source_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed={}", source_dir.display());
+
+    let tests: Vec<String> = find_fixtures(&source_dir)
+        .into_iter()
+        .map(|(name, day, input, expected)| {
+            format!(
+                "#[test]\nfn {name}() {{\n    check_fixture({day}, include_str!({input:?}), include_str!({expected:?}));\n}}\n"
+            )
+        })
+        .collect();
+
+    fs::write(out_dir.join("fixtures.rs"), tests.join("\n")).unwrap();
+}
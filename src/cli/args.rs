@@ -0,0 +1,516 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{solver::Part, util::STDIN_PATH};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    #[default]
+    Solve,
+    Bench,
+    Verify,
+    NewDay,
+    Generate,
+    Report,
+}
+
+impl Command {
+    fn name(&self) -> &'static str {
+        match self {
+            Command::Solve => "solve",
+            Command::Bench => "bench",
+            Command::Verify => "verify",
+            Command::NewDay => "new-day",
+            Command::Generate => "generate",
+            Command::Report => "report",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub inputs_dir: Option<PathBuf>,
+    pub user: Option<String>,
+    pub all: bool,
+    pub runs: Option<usize>,
+    pub jobs: Option<usize>,
+    pub seed: Option<u64>,
+    pub size: Option<usize>,
+    pub answers: Option<PathBuf>,
+    pub record: bool,
+    pub strict: bool,
+    pub format: Format,
+    pub watch: bool,
+    pub list: bool,
+    pub help: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ArgsError {
+    MissingValue(String),
+    InvalidValue(String, String),
+    UnknownArgument(String),
+    Conflict(&'static str, &'static str),
+    OnlyFor(&'static str, &'static str),
+    NoDaySelected,
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgsError::MissingValue(flag) => write!(f, "{flag} requires a value"),
+            ArgsError::InvalidValue(flag, value) => {
+                write!(f, "invalid value '{value}' for {flag}")
+            }
+            ArgsError::UnknownArgument(argument) => write!(f, "unknown argument '{argument}'"),
+            ArgsError::Conflict(a, b) => write!(f, "{a} cannot be combined with {b}"),
+            ArgsError::OnlyFor(flag, command) => {
+                write!(f, "{flag} can only be used with the {command} command")
+            }
+            ArgsError::NoDaySelected => write!(f, "select a day with --day or use --all"),
+        }
+    }
+}
+
+fn parse_value<T: FromStr>(flag: &str, value: Option<&String>) -> Result<T, ArgsError> {
+    let value = value.ok_or_else(|| ArgsError::MissingValue(flag.to_string()))?;
+
+    value
+        .parse()
+        .map_err(|_| ArgsError::InvalidValue(flag.to_string(), value.to_string()))
+}
+
+fn parse_part(value: Option<&String>) -> Result<Part, ArgsError> {
+    match parse_value::<u8>("--part", value)? {
+        1 => Ok(Part::One),
+        2 => Ok(Part::Two),
+        _ => Err(ArgsError::InvalidValue(
+            "--part".to_string(),
+            value.unwrap().to_string(),
+        )),
+    }
+}
+
+impl Args {
+    pub fn parse(arguments: &[String]) -> Result<Self, ArgsError> {
+        let mut args = Args::default();
+        let mut arguments = arguments.iter().peekable();
+
+        if let Some(command) = arguments.next_if(|argument| !argument.starts_with('-')) {
+            args.command = match command.as_str() {
+                "solve" => Command::Solve,
+                "bench" => Command::Bench,
+                "verify" => Command::Verify,
+                "new-day" => Command::NewDay,
+                "generate" => Command::Generate,
+                "report" => Command::Report,
+                _ => return Err(ArgsError::UnknownArgument(command.to_string())),
+            };
+        }
+
+        while let Some(argument) = arguments.next() {
+            match argument.as_str() {
+                "--day" | "-d" => args.day = Some(parse_value("--day", arguments.next())?),
+                "--part" | "-p" => args.part = Some(parse_part(arguments.next())?),
+                "--input" | "-i" => args.input = Some(parse_value("--input", arguments.next())?),
+                "--inputs-dir" => {
+                    args.inputs_dir = Some(parse_value("--inputs-dir", arguments.next())?)
+                }
+                "--user" | "-u" => args.user = Some(parse_value("--user", arguments.next())?),
+                "--all" | "-a" => args.all = true,
+                "--runs" | "-n" => args.runs = Some(parse_value("--runs", arguments.next())?),
+                "--jobs" | "-j" => args.jobs = Some(parse_value("--jobs", arguments.next())?),
+                "--seed" => args.seed = Some(parse_value("--seed", arguments.next())?),
+                "--size" => args.size = Some(parse_value("--size", arguments.next())?),
+                "--answers" => args.answers = Some(parse_value("--answers", arguments.next())?),
+                "--record" => args.record = true,
+                "--strict" => args.strict = true,
+                "--format" | "-f" => args.format = parse_value("--format", arguments.next())?,
+                "--watch" | "-w" => args.watch = true,
+                "--list" | "-l" => args.list = true,
+                "--help" | "-h" => args.help = true,
+                _ if matches!(
+                    args.command,
+                    Command::NewDay | Command::Generate | Command::Report
+                ) && args.day.is_none() =>
+                {
+                    args.day = Some(parse_value(args.command.name(), Some(argument))?)
+                }
+                _ => return Err(ArgsError::UnknownArgument(argument.to_string())),
+            }
+        }
+
+        if matches!(args.command, Command::Bench | Command::Verify) && args.day.is_none() {
+            args.all = true;
+        }
+
+        args.validate()?;
+
+        Ok(args)
+    }
+
+    fn validate(&self) -> Result<(), ArgsError> {
+        if self.help || self.list {
+            return Ok(());
+        }
+
+        if self.runs == Some(0) {
+            return Err(ArgsError::InvalidValue(
+                "--runs".to_string(),
+                "0".to_string(),
+            ));
+        }
+
+        if self.jobs == Some(0) {
+            return Err(ArgsError::InvalidValue(
+                "--jobs".to_string(),
+                "0".to_string(),
+            ));
+        }
+
+        if self.jobs.is_some() && self.command == Command::Bench {
+            return Err(ArgsError::Conflict("--jobs", Command::Bench.name()));
+        }
+
+        if self.runs.is_some() && self.command != Command::Bench {
+            return Err(ArgsError::OnlyFor("--runs", Command::Bench.name()));
+        }
+
+        if self.record && self.command != Command::Verify {
+            return Err(ArgsError::OnlyFor("--record", Command::Verify.name()));
+        }
+
+        if self.answers.is_some() && self.command != Command::Verify {
+            return Err(ArgsError::OnlyFor("--answers", Command::Verify.name()));
+        }
+
+        if self.strict && self.command != Command::Verify {
+            return Err(ArgsError::OnlyFor("--strict", Command::Verify.name()));
+        }
+
+        if self.strict && self.record {
+            return Err(ArgsError::Conflict("--strict", "--record"));
+        }
+
+        if self.format != Format::Text && self.command != Command::Solve {
+            return Err(ArgsError::OnlyFor("--format", Command::Solve.name()));
+        }
+
+        if self.seed.is_some() && self.command != Command::Generate {
+            return Err(ArgsError::OnlyFor("--seed", Command::Generate.name()));
+        }
+
+        if self.size.is_some() && self.command != Command::Generate {
+            return Err(ArgsError::OnlyFor("--size", Command::Generate.name()));
+        }
+
+        if matches!(self.command, Command::Generate | Command::Report) && self.all {
+            return Err(ArgsError::Conflict("--all", self.command.name()));
+        }
+
+        if self.watch && self.command != Command::Solve {
+            return Err(ArgsError::OnlyFor("--watch", Command::Solve.name()));
+        }
+
+        if self.watch && self.all {
+            return Err(ArgsError::Conflict("--watch", "--all"));
+        }
+
+        if self.watch && self.format != Format::Text {
+            return Err(ArgsError::Conflict("--watch", "--format json"));
+        }
+
+        if self.watch && self.input.as_deref() == Some(Path::new(STDIN_PATH)) {
+            return Err(ArgsError::Conflict("--watch", "--input -"));
+        }
+
+        if self.command == Command::NewDay {
+            return match self.day {
+                Some(1..=25) => Ok(()),
+                Some(day) => Err(ArgsError::InvalidValue(
+                    "new-day".to_string(),
+                    day.to_string(),
+                )),
+                None => Err(ArgsError::NoDaySelected),
+            };
+        }
+
+        if self.all && self.day.is_some() {
+            return Err(ArgsError::Conflict("--all", "--day"));
+        }
+
+        if self.all && self.input.is_some() {
+            return Err(ArgsError::Conflict("--all", "--input"));
+        }
+
+        if !self.all && self.day.is_none() {
+            return Err(ArgsError::NoDaySelected);
+        }
+
+        Ok(())
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::all().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_arguments(input: &str) -> Vec<String> {
+        input.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_day_part_and_input() {
+        let args = Args::parse(&to_arguments("--day 7 --part 2 --input other.txt")).unwrap();
+
+        assert_eq!(args.day, Some(7));
+        assert_eq!(args.part, Some(Part::Two));
+        assert_eq!(args.input, Some(PathBuf::from("other.txt")));
+        assert_eq!(args.parts(), vec![Part::Two]);
+    }
+
+    #[test]
+    fn parses_input_location() {
+        let args = Args::parse(&to_arguments("--all --inputs-dir inputs --user alice")).unwrap();
+
+        assert_eq!(args.inputs_dir, Some(PathBuf::from("inputs")));
+        assert_eq!(args.user, Some("alice".to_string()));
+    }
+
+    #[test]
+    fn parses_bench_command() {
+        let args = Args::parse(&to_arguments("bench --runs 5")).unwrap();
+
+        assert_eq!(args.command, Command::Bench);
+        assert_eq!(args.runs, Some(5));
+        assert!(args.all);
+
+        let args = Args::parse(&to_arguments("bench --day 4")).unwrap();
+        assert_eq!(args.day, Some(4));
+        assert!(!args.all);
+    }
+
+    #[test]
+    fn parses_verify_command() {
+        let args = Args::parse(&to_arguments("verify --record --answers a.toml")).unwrap();
+
+        assert_eq!(args.command, Command::Verify);
+        assert!(args.record);
+        assert!(args.all);
+        assert_eq!(args.answers, Some(PathBuf::from("a.toml")));
+
+        assert_eq!(
+            Args::parse(&to_arguments("--day 1 --record")),
+            Err(ArgsError::OnlyFor("--record", "verify"))
+        );
+
+        let args = Args::parse(&to_arguments("verify --day 2 --strict")).unwrap();
+        assert!(args.strict && !args.all);
+
+        assert_eq!(
+            Args::parse(&to_arguments("verify --strict --record")),
+            Err(ArgsError::Conflict("--strict", "--record"))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("--day 1 --strict")),
+            Err(ArgsError::OnlyFor("--strict", "verify"))
+        );
+    }
+
+    #[test]
+    fn parses_new_day_command() {
+        let args = Args::parse(&to_arguments("new-day 11")).unwrap();
+
+        assert_eq!(args.command, Command::NewDay);
+        assert_eq!(args.day, Some(11));
+        assert!(!args.all);
+
+        assert_eq!(
+            Args::parse(&to_arguments("new-day 26")),
+            Err(ArgsError::InvalidValue(
+                "new-day".to_string(),
+                "26".to_string()
+            ))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("new-day")),
+            Err(ArgsError::NoDaySelected)
+        );
+    }
+
+    #[test]
+    fn parses_generate_command() {
+        let args = Args::parse(&to_arguments("generate 8 --seed 42 --size 1")).unwrap();
+
+        assert_eq!(args.command, Command::Generate);
+        assert_eq!(
+            (args.day, args.seed, args.size),
+            (Some(8), Some(42), Some(1))
+        );
+
+        assert_eq!(
+            Args::parse(&to_arguments("--day 8 --seed 42")),
+            Err(ArgsError::OnlyFor("--seed", "generate"))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("generate --all")),
+            Err(ArgsError::Conflict("--all", "generate"))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("generate")),
+            Err(ArgsError::NoDaySelected)
+        );
+    }
+
+    #[test]
+    fn parses_report_command() {
+        let args = Args::parse(&to_arguments("report 1 -i other.txt")).unwrap();
+
+        assert_eq!(args.command, Command::Report);
+        assert_eq!(args.day, Some(1));
+        assert_eq!(args.input, Some(PathBuf::from("other.txt")));
+
+        assert_eq!(
+            Args::parse(&to_arguments("report --all")),
+            Err(ArgsError::Conflict("--all", "report"))
+        );
+    }
+
+    #[test]
+    fn parses_jobs() {
+        let args = Args::parse(&to_arguments("--all --jobs 3")).unwrap();
+        assert_eq!(args.jobs, Some(3));
+
+        assert_eq!(
+            Args::parse(&to_arguments("--all -j 0")),
+            Err(ArgsError::InvalidValue(
+                "--jobs".to_string(),
+                "0".to_string()
+            ))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("bench --jobs 2")),
+            Err(ArgsError::Conflict("--jobs", "bench"))
+        );
+    }
+
+    #[test]
+    fn parses_format() {
+        let args = Args::parse(&to_arguments("--all --format json")).unwrap();
+        assert_eq!(args.format, Format::Json);
+
+        assert_eq!(
+            Args::parse(&to_arguments("--all --format yaml")),
+            Err(ArgsError::InvalidValue(
+                "--format".to_string(),
+                "yaml".to_string()
+            ))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("bench --format json")),
+            Err(ArgsError::OnlyFor("--format", "solve"))
+        );
+    }
+
+    #[test]
+    fn parses_watch() {
+        let args = Args::parse(&to_arguments("--day 6 --watch")).unwrap();
+        assert!(args.watch);
+
+        assert_eq!(
+            Args::parse(&to_arguments("--all -w")),
+            Err(ArgsError::Conflict("--watch", "--all"))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("bench -d 6 -w")),
+            Err(ArgsError::OnlyFor("--watch", "solve"))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("-d 6 -w -i -")),
+            Err(ArgsError::Conflict("--watch", "--input -"))
+        );
+    }
+
+    #[test]
+    fn parses_all() {
+        let args = Args::parse(&to_arguments("--all")).unwrap();
+
+        assert!(args.all);
+        assert_eq!(args.parts(), vec![Part::One, Part::Two]);
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert_eq!(
+            Args::parse(&to_arguments("--day x")),
+            Err(ArgsError::InvalidValue(
+                "--day".to_string(),
+                "x".to_string()
+            ))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("--day 1 --part 3")),
+            Err(ArgsError::InvalidValue(
+                "--part".to_string(),
+                "3".to_string()
+            ))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("--day")),
+            Err(ArgsError::MissingValue("--day".to_string()))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("--all --day 1")),
+            Err(ArgsError::Conflict("--all", "--day"))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("--part 1")),
+            Err(ArgsError::NoDaySelected)
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("--day 1 --runs 3")),
+            Err(ArgsError::OnlyFor("--runs", "bench"))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("frobnicate --day 1")),
+            Err(ArgsError::UnknownArgument("frobnicate".to_string()))
+        );
+        assert_eq!(
+            Args::parse(&to_arguments("--verbose")),
+            Err(ArgsError::UnknownArgument("--verbose".to_string()))
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::{fmt, time::Duration};
+
+use crate::{
+    solver::{Part, Puzzle},
+    util::ParseError,
+};
+
+pub const DEFAULT_RUNS: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[middle - 1] + sorted[middle]) / 2,
+            _ => sorted[middle],
+        };
+
+        Self {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Benchmark {
+    pub day: u8,
+    pub stages: Vec<(Stage, Stats)>,
+}
+
+impl Benchmark {
+    pub fn total_median(&self) -> Duration {
+        self.stages.iter().map(|(_, stats)| stats.median).sum()
+    }
+}
+
+pub fn bench_puzzle(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<Benchmark, ParseError> {
+    let mut parse_samples = vec![];
+    let mut part_samples: Vec<Vec<Duration>> = vec![vec![]; parts.len()];
+
+    for _ in 0..runs {
+        let run = puzzle.run(input, parts)?;
+
+        parse_samples.push(run.parse_elapsed);
+        for (samples, solution) in part_samples.iter_mut().zip(run.solutions) {
+            samples.push(solution.elapsed);
+        }
+    }
+
+    let mut stages = vec![(Stage::Parse, Stats::from_samples(&parse_samples))];
+    for (part, samples) in parts.iter().zip(part_samples) {
+        stages.push((Stage::Solve(*part), Stats::from_samples(&samples)));
+    }
+
+    Ok(Benchmark {
+        day: puzzle.day(),
+        stages,
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    match nanos {
+        0..=999 => format!("{nanos}ns"),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+pub fn print_report(rows: &[Result<Benchmark, u8>], runs: usize) {
+    println!("{runs} runs per day\n");
+    println!(
+        "{:>4}  {:<7}  {:>10}  {:>10}  {:>10}",
+        "day", "stage", "min", "median", "max"
+    );
+
+    for row in rows {
+        let benchmark = match row {
+            Ok(benchmark) => benchmark,
+            Err(day) => {
+                println!(
+                    "{day:>4}  {:<7}  {:>10}  {:>10}  {:>10}",
+                    "error", "-", "-", "-"
+                );
+                continue;
+            }
+        };
+
+        for (stage, stats) in &benchmark.stages {
+            println!(
+                "{:>4}  {:<7}  {:>10}  {:>10}  {:>10}",
+                benchmark.day,
+                stage.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+            );
+        }
+    }
+
+    let benchmarks: Vec<&Benchmark> = rows.iter().filter_map(|row| row.as_ref().ok()).collect();
+    let mut by_total = benchmarks.clone();
+    by_total.sort_by_key(|benchmark| std::cmp::Reverse(benchmark.total_median()));
+
+    println!("\nsummary (median, slowest first)\n");
+    println!("{:>4}  {:>10}", "day", "total");
+    for benchmark in &by_total {
+        println!(
+            "{:>4}  {:>10}",
+            benchmark.day,
+            format_duration(benchmark.total_median())
+        );
+    }
+
+    let total: Duration = benchmarks
+        .iter()
+        .map(|benchmark| benchmark.total_median())
+        .sum();
+    println!("{:>4}  {:>10}", "all", format_duration(total));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        solver::{find_puzzle, Answer, Solver},
+        util::run_parallel,
+    };
+
+    struct Panicking;
+
+    impl Solver for Panicking {
+        const DAY: u8 = 24;
+
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn solve_part_1(_input: &Self::Input) -> Answer {
+            panic!("not implemented")
+        }
+
+        fn solve_part_2(_input: &Self::Input) -> Answer {
+            Answer::Number(0)
+        }
+    }
+
+    #[test]
+    fn computes_stats() {
+        let samples = [5, 1, 4, 2].map(Duration::from_millis);
+
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+
+        let stats = Stats::from_samples(&[7, 3, 9].map(Duration::from_millis));
+        assert_eq!(stats.median, Duration::from_millis(7));
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56ms");
+        assert_eq!(format_duration(Duration::from_millis(1_230)), "1.23s");
+    }
+
+    #[test]
+    fn benches_each_stage() {
+        let puzzle = find_puzzle(1).unwrap();
+
+        let benchmark = bench_puzzle(puzzle, "1000\n\n2000", &[Part::Two], 3).unwrap();
+
+        let stages: Vec<Stage> = benchmark.stages.iter().map(|(stage, _)| *stage).collect();
+        assert_eq!(stages, vec![Stage::Parse, Stage::Solve(Part::Two)]);
+    }
+
+    #[test]
+    fn isolates_panicking_days() {
+        let puzzles: Vec<&dyn Puzzle> = vec![&Panicking, find_puzzle(1).unwrap()];
+
+        let results = run_parallel(puzzles, 1, |puzzle| {
+            bench_puzzle(puzzle, "1000", &Part::all(), 2)
+        });
+
+        assert_eq!(results[0].as_ref().unwrap_err(), "not implemented");
+        assert_eq!(results[1].as_ref().unwrap().as_ref().unwrap().day, 1);
+    }
+}
@@ -0,0 +1,37 @@
+use std::{fmt, path::PathBuf};
+
+use crate::util::{InputError, ParseError};
+
+#[derive(Debug)]
+pub enum RunError {
+    Input(InputError),
+    Parse {
+        day: u8,
+        path: PathBuf,
+        source: ParseError,
+    },
+    Panic {
+        day: u8,
+        message: String,
+    },
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Input(error) => write!(f, "{error}"),
+            RunError::Parse { day, path, source } => write!(
+                f,
+                "could not parse input for day {day} in '{}', {source}",
+                path.display()
+            ),
+            RunError::Panic { day, message } => write!(f, "day {day} panicked: {message}"),
+        }
+    }
+}
+
+impl From<InputError> for RunError {
+    fn from(error: InputError) -> Self {
+        RunError::Input(error)
+    }
+}
@@ -0,0 +1,455 @@
+mod args;
+mod bench;
+mod error;
+mod report;
+mod scaffold;
+mod verify;
+mod watch;
+
+use std::{
+    io::stdin,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    solver::{find_puzzle, registry, AnswerSheet, Part, Puzzle, Run},
+    util::{default_workers, read_input, run_parallel, InputResolver},
+};
+use args::{Args, Command, Format};
+use error::RunError;
+
+const USAGE: &str = "usage: advent_of_code_2024 [command] [options]
+
+commands:
+  solve                   print the answers of the selected days (default)
+  bench                   time parsing and each part of the selected days (default: all days)
+  verify                  compare answers with the recorded answers file (default: all days)
+  new-day <n>             generate and register the module skeleton for day <n> (in ./src, else the crate's src/)
+  generate <n>            print a random input for day <n>
+  report <n>              print a statistics report for the input of day <n>
+
+options:
+  -d, --day <n>           select a single day
+  -a, --all               select every registered day
+  -p, --part <1|2>        only run the given part
+  -i, --input <path>      read the puzzle input from <path> (or stdin for -) instead of input.txt
+      --inputs-dir <path> look up day_XX/input.txt in <path> (default: $AOC_INPUTS_DIR or src/)
+  -u, --user <name>       use the input set in <inputs-dir>/<name> (default: $AOC_USER)
+  -j, --jobs <n>          number of days solved in parallel (default: available cores)
+  -n, --runs <n>          number of runs per day for bench (default: 10)
+      --seed <n>          seed for generate (default: derived from the current time)
+      --size <n>          size of the generated input (default: 100)
+      --answers <path>    answers file for verify (default: <inputs-dir>/answers.toml)
+      --record            record the current answers instead of verifying them
+      --strict            make verify fail on parts without a recorded answer
+  -f, --format <format>   print answers as text or as json records (default: text)
+  -w, --watch             re-run the selected day whenever its input or examples change
+  -l, --list              list the registered days
+  -h, --help              print this message
+
+without arguments the day is read interactively from stdin";
+
+type Outcome = Result<(PathBuf, Run), RunError>;
+
+fn load_input(
+    day: u8,
+    resolver: &InputResolver,
+    input_path: Option<&Path>,
+) -> Result<(PathBuf, String), RunError> {
+    match input_path {
+        Some(input_path) => Ok((input_path.to_path_buf(), read_input(day, input_path)?)),
+        None => Ok((resolver.input_path(day), resolver.read(day)?)),
+    }
+}
+
+fn solve_puzzle(
+    puzzle: &dyn Puzzle,
+    parts: &[Part],
+    resolver: &InputResolver,
+    input_path: Option<&Path>,
+) -> Outcome {
+    let day = puzzle.day();
+    let (path, input) = load_input(day, resolver, input_path)?;
+
+    match puzzle.run(&input, parts) {
+        Ok(run) => Ok((path, run)),
+        Err(source) => Err(RunError::Parse { day, path, source }),
+    }
+}
+
+fn solve_puzzles(
+    puzzles: Vec<&'static dyn Puzzle>,
+    args: &Args,
+    resolver: &InputResolver,
+) -> Vec<(u8, Outcome)> {
+    let days: Vec<u8> = puzzles.iter().map(|puzzle| puzzle.day()).collect();
+    let parts = args.parts();
+    let workers = args.jobs.unwrap_or_else(default_workers);
+
+    let results = run_parallel(puzzles, workers, |puzzle| {
+        solve_puzzle(puzzle, &parts, resolver, args.input.as_deref())
+    });
+
+    days.into_iter()
+        .zip(results)
+        .map(|(day, result)| {
+            let result = result.unwrap_or_else(|message| Err(RunError::Panic { day, message }));
+
+            (day, result)
+        })
+        .collect()
+}
+
+fn print_run(day: u8, run: &Run) {
+    println!("day {day}");
+    for solution in &run.solutions {
+        println!("part {}: {}", solution.part, solution.answer);
+    }
+}
+
+fn select_puzzles(args: &Args) -> Result<Vec<&'static dyn Puzzle>, ExitCode> {
+    match args.day {
+        Some(day) => match find_puzzle(day) {
+            Some(puzzle) => Ok(vec![puzzle]),
+            None => {
+                eprintln!("day {day} is not yet solved");
+                Err(ExitCode::FAILURE)
+            }
+        },
+        None => Ok(registry()),
+    }
+}
+
+fn solve(args: &Args, resolver: &InputResolver) -> ExitCode {
+    let puzzles = match select_puzzles(args) {
+        Ok(puzzles) => puzzles,
+        Err(code) => return code,
+    };
+    let mut records = vec![];
+    let mut result = ExitCode::SUCCESS;
+
+    for (day, outcome) in solve_puzzles(puzzles, args, resolver) {
+        match outcome {
+            Ok((path, run)) => match args.format {
+                Format::Text => print_run(day, &run),
+                Format::Json => records.extend(report::solution_records(day, &path, &run)),
+            },
+            Err(error) => {
+                eprintln!("error: {error}");
+                records.push(report::error_record(day, &error));
+                result = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if args.format == Format::Json {
+        report::print_records(&records);
+    }
+
+    result
+}
+
+fn watch(args: &Args, resolver: &InputResolver) -> ExitCode {
+    let puzzle = match select_puzzles(args) {
+        Ok(puzzles) => puzzles[0],
+        Err(code) => return code,
+    };
+    let day = puzzle.day();
+    let input_path = args
+        .input
+        .clone()
+        .unwrap_or_else(|| resolver.input_path(day));
+    let examples_dir = scaffold::source_dir()
+        .join(format!("day_{day:02}"))
+        .join("examples");
+    let mut watcher = watch::Watcher::new(&input_path, &examples_dir);
+    let mut previous = vec![];
+    let mut changed = vec![input_path];
+
+    loop {
+        if changed.iter().any(|path| !path.starts_with(&examples_dir)) {
+            for (_, outcome) in solve_puzzles(vec![puzzle], args, resolver) {
+                match outcome {
+                    Ok((_, run)) => {
+                        let answers = run.answers();
+                        watch::print_changes(day, &watch::diff_answers(&previous, &answers));
+                        previous = answers;
+                    }
+                    Err(error) => eprintln!("error: {error}"),
+                }
+            }
+        }
+
+        for example in watch::changed_examples(&changed, &examples_dir) {
+            match watch::check_example(puzzle, &example) {
+                Ok(results) => watch::print_example(&example, &results),
+                Err(error) => eprintln!("error: {error}"),
+            }
+        }
+
+        println!("watching {} files for changes", watcher.file_count());
+
+        changed = loop {
+            thread::sleep(watch::POLL_INTERVAL);
+
+            let changed = watcher.poll();
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+
+        for path in &changed {
+            println!("changed {}", path.display());
+        }
+    }
+}
+
+fn bench_puzzle(
+    puzzle: &dyn Puzzle,
+    parts: &[Part],
+    runs: usize,
+    resolver: &InputResolver,
+    input_path: Option<&Path>,
+) -> Result<bench::Benchmark, RunError> {
+    let day = puzzle.day();
+    let (path, input) = load_input(day, resolver, input_path)?;
+
+    bench::bench_puzzle(puzzle, &input, parts, runs).map_err(|source| RunError::Parse {
+        day,
+        path,
+        source,
+    })
+}
+
+fn bench(args: &Args, resolver: &InputResolver) -> ExitCode {
+    let puzzles = match select_puzzles(args) {
+        Ok(puzzles) => puzzles,
+        Err(code) => return code,
+    };
+    let runs = args.runs.unwrap_or(bench::DEFAULT_RUNS);
+    let parts = args.parts();
+    let days: Vec<u8> = puzzles.iter().map(|puzzle| puzzle.day()).collect();
+    let mut rows = vec![];
+    let mut result = ExitCode::SUCCESS;
+
+    let benchmarks = run_parallel(puzzles, 1, |puzzle| {
+        bench_puzzle(puzzle, &parts, runs, resolver, args.input.as_deref())
+    });
+
+    for (day, benchmark) in days.into_iter().zip(benchmarks) {
+        match benchmark.unwrap_or_else(|message| Err(RunError::Panic { day, message })) {
+            Ok(benchmark) => rows.push(Ok(benchmark)),
+            Err(error) => {
+                eprintln!("error: {error}");
+                rows.push(Err(day));
+                result = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    bench::print_report(&rows, runs);
+
+    result
+}
+
+fn verify(args: &Args, resolver: &InputResolver) -> ExitCode {
+    let puzzles = match select_puzzles(args) {
+        Ok(puzzles) => puzzles,
+        Err(code) => return code,
+    };
+    let answers_path = args
+        .answers
+        .clone()
+        .unwrap_or_else(|| resolver.inputs_dir().join("answers.toml"));
+    if !args.record && !answers_path.exists() {
+        eprintln!(
+            "error: answers file '{}' does not exist, create it with --record",
+            answers_path.display()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    let mut sheet = match AnswerSheet::load(&answers_path) {
+        Ok(sheet) => sheet,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let parts = args.parts();
+    let mut verifications = vec![];
+    let mut recorded = 0;
+
+    for (day, outcome) in solve_puzzles(puzzles, args, resolver) {
+        match outcome {
+            Ok((_, run)) if args.record => {
+                for solution in run.solutions {
+                    sheet.insert(day, solution.part, solution.answer);
+                    recorded += 1;
+                }
+            }
+            Ok((_, run)) => verifications.push(verify::verify_run(day, &run, &sheet)),
+            Err(error) => {
+                eprintln!("error: {error}");
+                verifications.push(verify::failed_verification(day, &parts));
+            }
+        }
+    }
+
+    if args.record {
+        if let Err(error) = sheet.save(&answers_path) {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+
+        println!(
+            "recorded {recorded} answers in '{}'",
+            answers_path.display()
+        );
+    } else {
+        verify::print_matrix(&verifications);
+    }
+
+    match verify::is_success(&verifications, args.strict) {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
+}
+
+fn new_day(args: &Args) -> ExitCode {
+    let day = args.day.unwrap();
+
+    match scaffold::scaffold_day(&scaffold::source_dir(), day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn report(args: &Args, resolver: &InputResolver) -> ExitCode {
+    let puzzle = match select_puzzles(args) {
+        Ok(puzzles) => puzzles[0],
+        Err(code) => return code,
+    };
+    let day = puzzle.day();
+    let (path, input) = match load_input(day, resolver, args.input.as_deref()) {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match puzzle.report(&input) {
+        Ok(Some(report)) => {
+            println!("{report}");
+            ExitCode::SUCCESS
+        }
+        Ok(None) => {
+            eprintln!("day {day} has no report");
+            ExitCode::FAILURE
+        }
+        Err(source) => {
+            eprintln!("error: {}", RunError::Parse { day, path, source });
+            ExitCode::FAILURE
+        }
+    }
+}
+
+const DEFAULT_GENERATED_SIZE: usize = 100;
+
+fn generate(args: &Args) -> ExitCode {
+    let puzzle = match select_puzzles(args) {
+        Ok(puzzles) => puzzles[0],
+        Err(code) => return code,
+    };
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    });
+    let size = args.size.unwrap_or(DEFAULT_GENERATED_SIZE);
+
+    match puzzle.generate(seed, size) {
+        Some(input) => {
+            eprintln!("seed {seed}");
+            println!("{input}");
+            ExitCode::SUCCESS
+        }
+        None => {
+            eprintln!("day {} has no input generator", puzzle.day());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_interactive() -> ExitCode {
+    println!("solve for day: ");
+    let mut buffer = String::new();
+    if let Err(error) = stdin().read_line(&mut buffer) {
+        eprintln!("error: could not read the day: {error}");
+        return ExitCode::FAILURE;
+    }
+
+    let day = match buffer.trim().parse() {
+        Ok(day) => day,
+        Err(_) => {
+            eprintln!("error: '{}' is not a valid day", buffer.trim());
+            return ExitCode::from(2);
+        }
+    };
+    let args = Args {
+        day: Some(day),
+        ..Args::default()
+    };
+
+    solve(&args, &InputResolver::from_env(None, None))
+}
+
+pub fn run(arguments: &[String]) -> ExitCode {
+    if arguments.is_empty() {
+        return run_interactive();
+    }
+
+    let args = match Args::parse(arguments) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("error: {error}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.help {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    if args.list {
+        for puzzle in registry() {
+            println!("day {}", puzzle.day());
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let resolver = InputResolver::from_env(args.inputs_dir.clone(), args.user.clone());
+
+    match args.command {
+        Command::Solve if args.watch => watch(&args, &resolver),
+        Command::Solve => solve(&args, &resolver),
+        Command::Bench => bench(&args, &resolver),
+        Command::Verify => verify(&args, &resolver),
+        Command::NewDay => new_day(&args),
+        Command::Generate => generate(&args),
+        Command::Report => report(&args, &resolver),
+    }
+}
@@ -0,0 +1,89 @@
+use std::{path::Path, time::Duration};
+
+use super::error::RunError;
+use crate::{
+    solver::{Answer, Run},
+    util::Json,
+};
+
+fn nanos(duration: Duration) -> Json {
+    Json::Number(duration.as_nanos().try_into().unwrap_or(i64::MAX))
+}
+
+fn answer_fields(answer: &Answer) -> (&'static str, Json) {
+    match answer {
+        Answer::Number(number) => ("number", Json::Number(*number)),
+        Answer::Text(text) => ("text", Json::from(text.as_str())),
+    }
+}
+
+pub fn solution_records(day: u8, path: &Path, run: &Run) -> Vec<Json> {
+    run.solutions
+        .iter()
+        .map(|solution| {
+            let (kind, answer) = answer_fields(&solution.answer);
+
+            Json::object()
+                .with("day", day)
+                .with("part", solution.part.number())
+                .with("type", kind)
+                .with("answer", answer)
+                .with("input", path.display().to_string())
+                .with("parse_elapsed_ns", nanos(run.parse_elapsed))
+                .with("elapsed_ns", nanos(solution.elapsed))
+        })
+        .collect()
+}
+
+pub fn error_record(day: u8, error: &RunError) -> Json {
+    Json::object()
+        .with("day", day)
+        .with("error", error.to_string())
+}
+
+pub fn print_records(records: &[Json]) {
+    match records.is_empty() {
+        true => println!("[]"),
+        false => {
+            let lines: Vec<String> = records.iter().map(|record| format!("  {record}")).collect();
+
+            println!("[\n{}\n]", lines.join(",\n"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{Part, Solution};
+
+    #[test]
+    fn writes_one_record_per_part() {
+        let run = Run {
+            parse_elapsed: Duration::from_nanos(5),
+            solutions: vec![
+                Solution {
+                    part: Part::One,
+                    answer: Answer::Number(13140),
+                    elapsed: Duration::from_nanos(7),
+                },
+                Solution {
+                    part: Part::Two,
+                    answer: Answer::Text("\n##..\n#..#".to_string()),
+                    elapsed: Duration::from_micros(1),
+                },
+            ],
+        };
+
+        let records = solution_records(10, Path::new("day_10/input.txt"), &run);
+
+        assert_eq!(
+            records[0].to_string(),
+            r#"{"day":10,"part":1,"type":"number","answer":13140,"input":"day_10/input.txt","parse_elapsed_ns":5,"elapsed_ns":7}"#
+        );
+        assert_eq!(
+            records[1].to_string(),
+            r#"{"day":10,"part":2,"type":"text","answer":"\n##..\n#..#","input":"day_10/input.txt","parse_elapsed_ns":5,"elapsed_ns":1000}"#
+        );
+    }
+}
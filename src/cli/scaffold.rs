@@ -0,0 +1,322 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const LINE_WIDTH: usize = 100;
+const PLACEHOLDER: &str = "not yet solved";
+
+pub fn source_dir() -> PathBuf {
+    let working_source = Path::new("src");
+
+    match working_source.join("solver").join("registry.rs").is_file() {
+        true => working_source.to_path_buf(),
+        false => Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+    }
+}
+
+fn module_template(day: u8) -> String {
+    format!(
+        r#"mod model;
+
+use crate::{{
+    solver::{{Answer, Solver}},
+    util::{{parse_lines, ParseError, Rng}},
+}};
+pub use model::Entry;
+
+const PLACEHOLDER: &str = "{PLACEHOLDER}";
+
+pub struct Day{day:02};
+
+impl Solver for Day{day:02} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<Entry>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {{
+        parse_lines(input)
+    }}
+
+    fn solve_part_1(_entries: &Self::Input) -> Answer {{
+        Answer::Text(PLACEHOLDER.to_string())
+    }}
+
+    fn solve_part_2(_entries: &Self::Input) -> Answer {{
+        Answer::Text(PLACEHOLDER.to_string())
+    }}
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {{
+        Some(
+            (0..size)
+                .map(|_| rng.below(100).to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }}
+}}
+
+#[cfg(test)]
+mod test {{
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("examples/example.txt");
+
+    #[test]
+    #[ignore = "add the example input and its answer"]
+    fn solves_example() {{
+        let input = Day{day:02}::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day{day:02}::solve_part_1(&input), Answer::Number(0));
+    }}
+}}
+"#
+    )
+}
+
+fn expected_template() -> String {
+    format!("part_1 = \"{PLACEHOLDER}\"\npart_2 = \"{PLACEHOLDER}\"\n")
+}
+
+fn model_template() -> String {
+    r#"use std::str::FromStr;
+
+use crate::util::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub text: String,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            text: input.to_string(),
+        })
+    }
+}
+"#
+    .to_string()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|error| format!("could not read '{}': {error}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents)
+        .map_err(|error| format!("could not write '{}': {error}", path.display()))
+}
+
+fn wrap_items(items: &[String], indent: usize) -> String {
+    let mut lines = vec![];
+    let mut line = " ".repeat(indent);
+
+    for item in items {
+        let item = format!("{item},");
+
+        if line.trim().is_empty() {
+            line.push_str(&item);
+        } else if line.len() + 1 + item.len() > LINE_WIDTH {
+            lines.push(line);
+            line = format!("{}{item}", " ".repeat(indent));
+        } else {
+            line.push(' ');
+            line.push_str(&item);
+        }
+    }
+    lines.push(line);
+
+    lines.join("\n")
+}
+
+fn register_module(lib: &str, day: u8) -> String {
+    let module = format!("pub mod day_{day:02};");
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let position = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod ") && *line > module.as_str())
+        .unwrap_or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with("pub mod "))
+                .map_or(0, |position| position + 1)
+        });
+
+    lines.insert(position, &module);
+
+    lines.join("\n") + "\n"
+}
+
+fn replace_block(source: &str, open: &str, close: &str, body: &str) -> Result<String, String> {
+    let start = source
+        .find(open)
+        .ok_or_else(|| format!("could not find '{open}' in the registry"))?
+        + open.len();
+    let end = source[start..]
+        .find(close)
+        .ok_or_else(|| format!("could not find '{close}' in the registry"))?
+        + start;
+
+    Ok(format!("{}\n{body}\n{}", &source[..start], &source[end..]))
+}
+
+fn registered_days(registry: &str) -> Vec<u8> {
+    registry
+        .split("day_")
+        .skip(1)
+        .filter_map(|rest| rest.get(..2)?.parse().ok())
+        .collect()
+}
+
+fn register_puzzle(registry: &str, day: u8) -> Result<String, String> {
+    let mut days = registered_days(registry);
+    days.push(day);
+    days.sort();
+    days.dedup();
+
+    let imports: Vec<String> = days
+        .iter()
+        .map(|day| format!("day_{day:02}::Day{day:02}"))
+        .collect();
+    let puzzles: Vec<String> = days.iter().map(|day| format!("&Day{day:02}")).collect();
+
+    let registry = replace_block(registry, "use crate::{", "};", &wrap_items(&imports, 4))?;
+
+    replace_block(&registry, "vec![", "    ]", &wrap_items(&puzzles, 8))
+}
+
+pub fn scaffold_day(source_dir: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let day_dir = source_dir.join(format!("day_{day:02}"));
+
+    if day_dir.exists() {
+        return Err(format!(
+            "day {day} already exists in '{}', refusing to overwrite it",
+            day_dir.display()
+        ));
+    }
+
+    let lib_path = source_dir.join("lib.rs");
+    let registry_path = source_dir.join("solver").join("registry.rs");
+    let lib = register_module(&read(&lib_path)?, day);
+    let registry = register_puzzle(&read(&registry_path)?, day)?;
+
+    let examples_dir = day_dir.join("examples");
+    fs::create_dir_all(&examples_dir)
+        .map_err(|error| format!("could not create '{}': {error}", examples_dir.display()))?;
+
+    let module_path = day_dir.join("mod.rs");
+    let model_path = day_dir.join("model.rs");
+    let input_path = day_dir.join("input.txt");
+    let example_path = examples_dir.join("example.txt");
+    let expected_path = examples_dir.join("example.expected");
+    write(&module_path, &module_template(day))?;
+    write(&model_path, &model_template())?;
+    write(&input_path, "")?;
+    write(&example_path, "")?;
+    write(&expected_path, &expected_template())?;
+    write(&lib_path, &lib)?;
+    write(&registry_path, &registry)?;
+
+    Ok(vec![
+        module_path,
+        model_path,
+        input_path,
+        example_path,
+        expected_path,
+        lib_path,
+        registry_path,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod cli;\npub mod day_01;\npub mod day_02;\npub mod model;\n\npub use solver::Solver;\n";
+    const REGISTRY: &str = "use super::Puzzle;
+use crate::{
+    day_01::Day01, day_02::Day02,
+};
+
+pub fn registry() -> Vec<&'static dyn Puzzle> {
+    vec![
+        &Day01, &Day02,
+    ]
+}
+";
+
+    fn temporary_source_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("solver")).unwrap();
+        fs::write(dir.join("lib.rs"), LIB).unwrap();
+        fs::write(dir.join("solver").join("registry.rs"), REGISTRY).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn creates_and_registers_day() {
+        let dir = temporary_source_dir("create");
+
+        scaffold_day(&dir, 3).unwrap();
+
+        let module = fs::read_to_string(dir.join("day_03").join("mod.rs")).unwrap();
+        assert!(module.contains("pub struct Day03;"));
+        assert!(module.contains("const DAY: u8 = 3;"));
+        assert!(!module.contains("todo!()"));
+        assert!(fs::read_to_string(dir.join("day_03").join("model.rs"))
+            .unwrap()
+            .contains("impl FromStr for Entry"));
+        assert!(dir.join("day_03").join("input.txt").exists());
+        assert!(dir
+            .join("day_03")
+            .join("examples")
+            .join("example.txt")
+            .exists());
+        assert_eq!(
+            fs::read_to_string(dir.join("day_03").join("examples").join("example.expected"))
+                .unwrap(),
+            "part_1 = \"not yet solved\"\npart_2 = \"not yet solved\"\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("lib.rs")).unwrap(),
+            "pub mod cli;\npub mod day_01;\npub mod day_02;\npub mod day_03;\npub mod model;\n\npub use solver::Solver;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("solver").join("registry.rs")).unwrap(),
+            REGISTRY
+                .replace("day_02::Day02,", "day_02::Day02, day_03::Day03,")
+                .replace("&Day02,", "&Day02, &Day03,")
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_to_overwrite_existing_day() {
+        let dir = temporary_source_dir("overwrite");
+        fs::create_dir_all(dir.join("day_02")).unwrap();
+
+        let error = scaffold_day(&dir, 2).unwrap_err();
+
+        assert!(error.contains("already exists"));
+        assert_eq!(fs::read_to_string(dir.join("lib.rs")).unwrap(), LIB);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn wraps_long_registrations() {
+        let items: Vec<String> = (1..=11).map(|day| format!("&Day{day:02}")).collect();
+
+        assert_eq!(
+            wrap_items(&items, 8),
+            "        &Day01, &Day02, &Day03, &Day04, &Day05, &Day06, &Day07, &Day08, &Day09, &Day10, &Day11,"
+        );
+    }
+}
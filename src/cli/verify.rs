@@ -0,0 +1,151 @@
+use crate::solver::{Answer, AnswerSheet, Part, Run};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: Answer, actual: Answer },
+    Unrecorded,
+    Error,
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Unrecorded => "-",
+            Status::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub results: Vec<(Part, Status)>,
+}
+
+pub fn verify_run(day: u8, run: &Run, sheet: &AnswerSheet) -> Verification {
+    let results = run
+        .solutions
+        .iter()
+        .map(|solution| {
+            let status = match sheet.get(day, solution.part) {
+                None => Status::Unrecorded,
+                Some(expected) if *expected == solution.answer => Status::Pass,
+                Some(expected) => Status::Fail {
+                    expected: expected.clone(),
+                    actual: solution.answer.clone(),
+                },
+            };
+
+            (solution.part, status)
+        })
+        .collect();
+
+    Verification { day, results }
+}
+
+pub fn failed_verification(day: u8, parts: &[Part]) -> Verification {
+    Verification {
+        day,
+        results: parts.iter().map(|part| (*part, Status::Error)).collect(),
+    }
+}
+
+pub fn is_success(verifications: &[Verification], strict: bool) -> bool {
+    verifications.iter().all(|verification| {
+        verification.results.iter().all(|(_, status)| match status {
+            Status::Pass => true,
+            Status::Unrecorded => !strict,
+            _ => false,
+        })
+    })
+}
+
+fn status_for(verification: &Verification, part: Part) -> &'static str {
+    verification
+        .results
+        .iter()
+        .find(|(result_part, _)| *result_part == part)
+        .map(|(_, status)| status.label())
+        .unwrap_or("")
+}
+
+pub fn print_matrix(verifications: &[Verification]) {
+    println!("{:>4}  {:<7}  {:<7}", "day", "part 1", "part 2");
+
+    for verification in verifications {
+        println!(
+            "{:>4}  {:<7}  {:<7}",
+            verification.day,
+            status_for(verification, Part::One),
+            status_for(verification, Part::Two),
+        );
+    }
+
+    for verification in verifications {
+        for (part, status) in &verification.results {
+            if let Status::Fail { expected, actual } = status {
+                println!(
+                    "\nday {} part {part}:\n  expected: {expected}\n  actual:   {actual}",
+                    verification.day
+                );
+            }
+        }
+    }
+
+    let count = |matches: fn(&Status) -> bool| {
+        verifications
+            .iter()
+            .flat_map(|verification| &verification.results)
+            .filter(|(_, status)| matches(status))
+            .count()
+    };
+
+    println!(
+        "\n{} passed, {} failed, {} unrecorded, {} errors",
+        count(|status| matches!(status, Status::Pass)),
+        count(|status| matches!(status, Status::Fail { .. })),
+        count(|status| matches!(status, Status::Unrecorded)),
+        count(|status| matches!(status, Status::Error)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::find_puzzle;
+
+    #[test]
+    fn compares_answers_with_sheet() {
+        let run = find_puzzle(1)
+            .unwrap()
+            .run("1000\n2000\n\n4000", &Part::all())
+            .unwrap();
+
+        let mut sheet = AnswerSheet::default();
+        sheet.insert(1, Part::One, Answer::Number(4000));
+        let verification = verify_run(1, &run, &sheet);
+        assert_eq!(
+            verification.results,
+            vec![(Part::One, Status::Pass), (Part::Two, Status::Unrecorded)]
+        );
+        assert!(is_success(std::slice::from_ref(&verification), false));
+        assert!(!is_success(&[verification], true));
+
+        sheet.insert(1, Part::Two, Answer::Number(1));
+        let verification = verify_run(1, &run, &sheet);
+        assert_eq!(
+            verification.results[1],
+            (
+                Part::Two,
+                Status::Fail {
+                    expected: Answer::Number(1),
+                    actual: Answer::Number(7000)
+                }
+            )
+        );
+        assert!(!is_success(&[verification], false));
+    }
+}
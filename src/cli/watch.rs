@@ -0,0 +1,264 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use super::verify::{self, Status};
+use crate::solver::{Answer, AnswerSheet, Part, Puzzle};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct Watcher {
+    input_path: PathBuf,
+    examples_dir: PathBuf,
+    modified: BTreeMap<PathBuf, SystemTime>,
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+impl Watcher {
+    pub fn new(input_path: &Path, examples_dir: &Path) -> Self {
+        let mut watcher = Self {
+            input_path: input_path.to_path_buf(),
+            examples_dir: examples_dir.to_path_buf(),
+            modified: BTreeMap::new(),
+        };
+        watcher.modified = watcher.snapshot();
+
+        watcher
+    }
+
+    fn snapshot(&self) -> BTreeMap<PathBuf, SystemTime> {
+        let examples = fs::read_dir(&self.examples_dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()));
+
+        [self.input_path.clone()]
+            .into_iter()
+            .chain(examples)
+            .filter_map(|path| modified_at(&path).map(|modified| (path, modified)))
+            .collect()
+    }
+
+    pub fn file_count(&self) -> usize {
+        self.modified.len()
+    }
+
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let snapshot = self.snapshot();
+        let mut changed: Vec<PathBuf> = snapshot
+            .iter()
+            .filter(|(path, modified)| self.modified.get(*path) != Some(modified))
+            .chain(
+                self.modified
+                    .iter()
+                    .filter(|(path, _)| !snapshot.contains_key(*path)),
+            )
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        changed.sort();
+        self.modified = snapshot;
+
+        changed
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerChange {
+    New(Answer),
+    Unchanged(Answer),
+    Changed { previous: Answer, current: Answer },
+}
+
+impl fmt::Display for AnswerChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswerChange::New(answer) => write!(f, "{answer}"),
+            AnswerChange::Unchanged(answer) => write!(f, "{answer} (unchanged)"),
+            AnswerChange::Changed { previous, current } => {
+                write!(f, "{current} (was {previous})")
+            }
+        }
+    }
+}
+
+pub fn diff_answers(
+    previous: &[(Part, Answer)],
+    current: &[(Part, Answer)],
+) -> Vec<(Part, AnswerChange)> {
+    current
+        .iter()
+        .map(|(part, answer)| {
+            let previous = previous
+                .iter()
+                .find(|(previous_part, _)| previous_part == part)
+                .map(|(_, previous)| previous);
+
+            let change = match previous {
+                None => AnswerChange::New(answer.clone()),
+                Some(previous) if previous == answer => AnswerChange::Unchanged(answer.clone()),
+                Some(previous) => AnswerChange::Changed {
+                    previous: previous.clone(),
+                    current: answer.clone(),
+                },
+            };
+
+            (*part, change)
+        })
+        .collect()
+}
+
+pub fn print_changes(day: u8, changes: &[(Part, AnswerChange)]) {
+    println!("day {day}");
+    for (part, change) in changes {
+        println!("part {part}: {change}");
+    }
+}
+
+pub fn changed_examples(changed: &[PathBuf], examples_dir: &Path) -> Vec<PathBuf> {
+    let mut examples: Vec<PathBuf> = changed
+        .iter()
+        .filter(|path| path.starts_with(examples_dir))
+        .map(|path| path.with_extension("txt"))
+        .filter(|path| path.exists())
+        .collect();
+
+    examples.dedup();
+    examples
+}
+
+pub fn check_example(puzzle: &dyn Puzzle, path: &Path) -> Result<Vec<(Part, Status)>, String> {
+    let day = puzzle.day();
+    let expected_path = path.with_extension("expected");
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|error| format!("{}: {error}", path.display()))
+    };
+
+    let expected = AnswerSheet::parse_day(day, &read(&expected_path)?)
+        .map_err(|error| format!("{}: {error}", expected_path.display()))?;
+    let parts: Vec<Part> = Part::all()
+        .into_iter()
+        .filter(|part| expected.get(day, *part).is_some())
+        .collect();
+    let run = puzzle
+        .run(&read(path)?, &parts)
+        .map_err(|error| format!("{}: {error}", path.display()))?;
+
+    Ok(verify::verify_run(day, &run, &expected).results)
+}
+
+pub fn print_example(path: &Path, results: &[(Part, Status)]) {
+    let name = path.file_stem().unwrap_or_default().to_string_lossy();
+
+    println!("example {name}");
+    for (part, status) in results {
+        match status {
+            Status::Fail { expected, actual } => {
+                println!("part {part}: FAIL\n  expected: {expected}\n  actual:   {actual}")
+            }
+            _ => println!("part {part}: {}", status.label()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_answers_against_previous_run() {
+        let previous = vec![
+            (Part::One, Answer::Number(1)),
+            (Part::Two, Answer::Number(2)),
+        ];
+        let current = vec![
+            (Part::One, Answer::Number(1)),
+            (Part::Two, Answer::Number(3)),
+        ];
+
+        let changes = diff_answers(&previous, &current);
+
+        assert_eq!(
+            changes,
+            vec![
+                (Part::One, AnswerChange::Unchanged(Answer::Number(1))),
+                (
+                    Part::Two,
+                    AnswerChange::Changed {
+                        previous: Answer::Number(2),
+                        current: Answer::Number(3)
+                    }
+                ),
+            ]
+        );
+        assert_eq!(changes[1].1.to_string(), "3 (was 2)");
+        assert_eq!(
+            diff_answers(&[], &current)[0].1,
+            AnswerChange::New(Answer::Number(1))
+        );
+    }
+
+    #[test]
+    fn checks_changed_examples() {
+        let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/day_01/examples");
+        let example = examples_dir.join("example.txt");
+        let changed = vec![
+            examples_dir.join("example.expected"),
+            example.clone(),
+            PathBuf::from("input.txt"),
+        ];
+
+        assert_eq!(
+            changed_examples(&changed, &examples_dir),
+            vec![example.clone()]
+        );
+
+        let puzzle = crate::solver::find_puzzle(1).unwrap();
+        assert_eq!(
+            check_example(puzzle, &example).unwrap(),
+            vec![(Part::One, Status::Pass), (Part::Two, Status::Pass)]
+        );
+        assert!(check_example(puzzle, &examples_dir.join("missing.txt")).is_err());
+    }
+
+    #[test]
+    fn detects_modified_and_new_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let examples_dir = dir.join("examples");
+        let input_path = dir.join("input.txt");
+        fs::create_dir_all(&examples_dir).unwrap();
+        fs::write(&input_path, "1").unwrap();
+
+        let mut watcher = Watcher::new(&input_path, &examples_dir);
+        assert_eq!(watcher.file_count(), 1);
+        assert!(watcher.poll().is_empty());
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        fs::File::options()
+            .write(true)
+            .open(&input_path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        fs::write(examples_dir.join("example.txt"), "2").unwrap();
+
+        assert_eq!(
+            watcher.poll(),
+            vec![examples_dir.join("example.txt"), input_path.clone()]
+        );
+        assert!(watcher.poll().is_empty());
+
+        fs::remove_file(&input_path).unwrap();
+        assert_eq!(watcher.poll(), vec![input_path]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,29 +1,118 @@
-use std::cmp::Ordering;
+use std::{fmt, str::FromStr};
 
-#[derive(Debug, PartialEq, Eq)]
+use crate::util::{
+    combinators::{consumed, expecting, lines, parse_all, unsigned, Parser, Scanner},
+    ParseError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Elf {
-    pub total: u32,
+    pub index: usize,
+    pub items: Vec<u32>,
+}
+
+pub const TOTAL_OVERFLOW: &str = "a calorie total that fits in 32 bits";
+
+pub fn inventory<'a>() -> impl Parser<'a, Vec<u32>> {
+    let items = lines(consumed(expecting("a calorie count", unsigned::<u32>())));
+
+    move |scanner: &mut Scanner<'a>| {
+        let items = items(scanner)?;
+        let mut total: u32 = 0;
+
+        for &(item, token) in &items {
+            total = total
+                .checked_add(item)
+                .ok_or_else(|| scanner.error_at(token, TOTAL_OVERFLOW))?;
+        }
+
+        Ok(items.into_iter().map(|(item, _)| item).collect())
+    }
 }
 
 impl Elf {
-    pub fn from_string(input: &str) -> Self {
-        let total = input
-            .lines()
-            .map(|number| number.parse::<u32>().unwrap())
-            .sum();
+    pub fn new(index: usize, items: Vec<u32>) -> Self {
+        Self { index, items }
+    }
+
+    pub fn number(&self) -> usize {
+        self.index + 1
+    }
+
+    pub fn total(&self) -> u32 {
+        self.items.iter().sum()
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
 
-        Self { total }
+    pub fn largest_item(&self) -> Option<u32> {
+        self.items.iter().copied().max()
     }
 }
 
-impl PartialOrd for Elf {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.total.cmp(&other.total))
+impl FromStr for Elf {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_all(input, inventory()).map(|items| Elf::new(0, items))
     }
 }
 
-impl Ord for Elf {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "elf {}: {} calories in {} items",
+            self.number(),
+            self.total(),
+            self.item_count()
+        )?;
+
+        match self.largest_item() {
+            Some(largest) => write!(f, " (largest {largest})"),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_elf() {
+        let elf: Elf = "1000\n2000\n3000".parse().unwrap();
+
+        assert_eq!(elf, Elf::new(0, vec![1000, 2000, 3000]));
+        assert_eq!(elf.total(), 6000);
+        assert_eq!(elf.item_count(), 3);
+        assert_eq!(elf.largest_item(), Some(3000));
+    }
+
+    #[test]
+    fn describes_elf() {
+        let elf = Elf::new(3, vec![7000, 8000, 9000]);
+        assert_eq!(
+            elf.to_string(),
+            "elf 4: 24000 calories in 3 items (largest 9000)"
+        );
+
+        let empty = Elf::new(0, vec![]);
+        assert_eq!(empty.largest_item(), None);
+        assert_eq!(empty.to_string(), "elf 1: 0 calories in 0 items");
+    }
+
+    #[test]
+    fn rejects_invalid_calories() {
+        let error = "1000\n2x00".parse::<Elf>().unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.token, "2x00");
+
+        let error = "4294967295\n0\n1".parse::<Elf>().unwrap_err();
+        assert_eq!((error.line, error.token.as_str()), (3, "1"));
+        assert_eq!(error.expected, TOTAL_OVERFLOW);
     }
 }
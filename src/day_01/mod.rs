@@ -1,37 +1,125 @@
 mod elf;
+mod rebalance;
+mod stats;
+mod streaming;
 
-use crate::util::read_input;
-use elf::Elf;
+use std::cmp::Reverse;
 
-fn find_max_elf_calories(input: &str) -> u32 {
-    input
-        .split("\n\n")
-        .map(|input| Elf::from_string(input).total)
-        .max()
-        .unwrap()
+use crate::{
+    solver::{Answer, Solver},
+    util::{
+        combinators::{blocks, map, parse_all},
+        ParseError, Rng,
+    },
+};
+pub use elf::Elf;
+pub use rebalance::{
+    rebalance, rebalance_exact, rebalance_greedy, rebalance_under_cap, Move, Plan, RebalanceError,
+    Strategy,
+};
+pub use stats::{histogram, outliers, percentile, statistics_table, Bucket, CalorieStats};
+pub use streaming::{top_totals, StreamError, TopK};
+
+const HISTOGRAM_BUCKETS: usize = 8;
+
+fn parse_elves(input: &str) -> Result<Vec<Elf>, ParseError> {
+    let elves = map(blocks(elf::inventory()), |inventories| {
+        inventories
+            .into_iter()
+            .enumerate()
+            .map(|(index, items)| Elf::new(index, items))
+            .collect()
+    });
+
+    parse_all(input, elves)
 }
 
-pub fn solve_part_1() -> u32 {
-    let input = read_input("src/day_01/input.txt");
+fn generate_elves(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let items = match rng.chance(1, 10) {
+                true => 0,
+                false => 1 + rng.below(6),
+            };
 
-    find_max_elf_calories(&input)
+            (0..items)
+                .map(|_| rng.range(1..=60000).to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
 }
 
-fn find_most_elf_calories(input: &str, take: usize) -> u32 {
-    let mut elves: Vec<Elf> = input
-        .split("\n\n")
-        .map(|input| Elf::from_string(input))
-        .collect();
+pub fn top_elves(elves: &[Elf], take: usize) -> Vec<&Elf> {
+    let mut elves: Vec<&Elf> = elves.iter().collect();
 
-    elves.sort();
+    elves.sort_by_key(|elf| (Reverse(elf.total()), elf.index));
+    elves.truncate(take);
 
-    elves.iter().rev().take(take).map(|elf| elf.total).sum()
+    elves
 }
 
-pub fn solve_part_2() -> u32 {
-    let input = read_input("src/day_01/input.txt");
+pub fn find_largest_item(elves: &[Elf]) -> Option<(&Elf, u32)> {
+    elves
+        .iter()
+        .filter_map(|elf| elf.largest_item().map(|largest| (elf, largest)))
+        .max_by_key(|(elf, largest)| (*largest, Reverse(elf.index)))
+}
+
+pub fn top_elves_report(elves: &[Elf], take: usize) -> String {
+    top_elves(elves, take)
+        .into_iter()
+        .map(|elf| {
+            let items: Vec<String> = elf.items.iter().map(|item| item.to_string()).collect();
+
+            match items.is_empty() {
+                true => format!("{elf}\n  (no items)"),
+                false => format!("{elf}\n  {}", items.join(", ")),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    find_most_elf_calories(&input, 3)
+fn find_max_elf_calories(elves: &[Elf]) -> u32 {
+    find_most_elf_calories(elves, 1)
+}
+
+fn find_most_elf_calories(elves: &[Elf], take: usize) -> u32 {
+    top_elves(elves, take).iter().map(|elf| elf.total()).sum()
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<Elf>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_elves(input)
+    }
+
+    fn solve_part_1(elves: &Self::Input) -> Answer {
+        find_max_elf_calories(elves).into()
+    }
+
+    fn solve_part_2(elves: &Self::Input) -> Answer {
+        find_most_elf_calories(elves, 3).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_elves(rng, size))
+    }
+
+    fn report(elves: &Self::Input) -> Option<String> {
+        Some(format!(
+            "{}\n\ntop elves\n{}",
+            statistics_table(elves, HISTOGRAM_BUCKETS),
+            top_elves_report(elves, 3)
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -40,17 +128,83 @@ mod test {
 
     #[test]
     fn finds_max_elf_calories() {
-        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        let input = include_str!("examples/example.txt");
 
-        let result = find_max_elf_calories(input);
+        let result = find_max_elf_calories(&parse_elves(input).unwrap());
         assert_eq!(result, 24000);
     }
 
     #[test]
     fn finds_most_elf_calories() {
-        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        let input = include_str!("examples/example.txt");
 
-        let result = find_most_elf_calories(input, 3);
+        let result = find_most_elf_calories(&parse_elves(input).unwrap(), 3);
         assert_eq!(result, 45000);
     }
+
+    #[test]
+    fn ranks_elves_by_total() {
+        let elves = parse_elves(include_str!("examples/example.txt")).unwrap();
+        let top: Vec<usize> = top_elves(&elves, 3).iter().map(|elf| elf.index).collect();
+
+        assert_eq!(top, vec![3, 2, 4]);
+        assert_eq!(top_elves(&elves, 10).len(), 5);
+
+        let (elf, largest) = find_largest_item(&elves).unwrap();
+        assert_eq!((elf.index, largest), (4, 10000));
+    }
+
+    #[test]
+    fn breaks_ties_by_input_order() {
+        let elves = parse_elves("300\n\n100\n200\n\n300").unwrap();
+        let top: Vec<usize> = top_elves(&elves, 3).iter().map(|elf| elf.index).collect();
+
+        assert_eq!(top, vec![0, 1, 2]);
+        assert_eq!(find_largest_item(&elves).map(|(elf, _)| elf.index), Some(0));
+    }
+
+    #[test]
+    fn reports_top_elves_with_items() {
+        let elves = parse_elves(include_str!("examples/example.txt")).unwrap();
+
+        assert_eq!(
+            top_elves_report(&elves, 2),
+            "elf 4: 24000 calories in 3 items (largest 9000)\n  7000, 8000, 9000\n\
+             elf 3: 11000 calories in 2 items (largest 6000)\n  5000, 6000"
+        );
+    }
+
+    #[test]
+    fn streams_same_totals_as_parsed_elves() {
+        for seed in 0..20 {
+            let input = generate_elves(&mut Rng::new(seed), 50);
+            let elves = parse_elves(&input).unwrap();
+
+            for take in [1, 3, 60] {
+                let totals = top_totals(input.as_bytes(), take).unwrap();
+
+                assert_eq!(
+                    totals.iter().sum::<u32>(),
+                    find_most_elf_calories(&elves, take)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn handles_empty_elves() {
+        let elves = parse_elves("1000\n\n\n\n2000").unwrap();
+        assert_eq!(elves[1], Elf::new(1, vec![]));
+        assert_eq!(find_most_elf_calories(&elves, 3), 3000);
+
+        assert_eq!(find_max_elf_calories(&parse_elves("").unwrap()), 0);
+    }
+
+    #[test]
+    fn reports_line_of_invalid_calories() {
+        let input = "1000\n2000\n\n4000\n\n50x0";
+
+        let error = parse_elves(input).unwrap_err();
+        assert_eq!(error.line, 6);
+    }
 }
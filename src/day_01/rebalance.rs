@@ -0,0 +1,488 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap, HashMap},
+    error, fmt,
+};
+
+use super::Elf;
+
+const EXACT_ITEM_LIMIT: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    Unchanged,
+    Exact,
+    Greedy,
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Strategy::Unchanged => write!(f, "unchanged"),
+            Strategy::Exact => write!(f, "exact search"),
+            Strategy::Greedy => write!(f, "longest processing time"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub item: u32,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "move {} from elf {} to elf {}",
+            self.item,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub strategy: Strategy,
+    pub loads: BTreeMap<usize, u32>,
+    pub moves: Vec<Move>,
+    pub lower_bound: u32,
+}
+
+impl Plan {
+    pub fn max_load(&self) -> u32 {
+        self.loads.values().copied().max().unwrap_or(0)
+    }
+
+    pub fn min_load(&self) -> u32 {
+        self.loads.values().copied().min().unwrap_or(0)
+    }
+
+    pub fn imbalance(&self) -> u32 {
+        self.max_load() - self.min_load()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows = [
+            ("strategy", self.strategy.to_string()),
+            ("max load", self.max_load().to_string()),
+            ("lower bound", self.lower_bound.to_string()),
+            ("imbalance", self.imbalance().to_string()),
+            ("moves", self.moves.len().to_string()),
+        ];
+        let label_width = rows.iter().map(|(label, _)| label.len()).max().unwrap();
+        let value_width = rows.iter().map(|(_, value)| value.len()).max().unwrap();
+
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|(label, value)| format!("{label:<label_width$}  {value:>value_width$}"))
+            .chain(self.moves.iter().map(|step| format!("  {step}")))
+            .collect();
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RebalanceError {
+    ItemTooLarge { elf: usize, item: u32, cap: u32 },
+    Infeasible { cap: u32, best: u32 },
+    NoPlanFound { cap: u32, best: u32 },
+}
+
+impl fmt::Display for RebalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RebalanceError::ItemTooLarge { elf, item, cap } => write!(
+                f,
+                "elf {} carries an item of {item} calories, over the cap of {cap}",
+                elf + 1
+            ),
+            RebalanceError::Infeasible { cap, best } => write!(
+                f,
+                "could not bring every elf under {cap} calories (best found {best})"
+            ),
+            RebalanceError::NoPlanFound { cap, best } => write!(
+                f,
+                "the greedy heuristic found no plan under {cap} calories (best found {best})"
+            ),
+        }
+    }
+}
+
+impl error::Error for RebalanceError {}
+
+#[derive(Debug, Clone, Copy)]
+struct Item {
+    owner: usize,
+    value: u32,
+}
+
+fn sorted_items(elves: &[Elf]) -> Vec<Item> {
+    let mut items: Vec<Item> = elves
+        .iter()
+        .enumerate()
+        .flat_map(|(owner, elf)| elf.items.iter().map(move |&value| Item { owner, value }))
+        .collect();
+    items.sort_by_key(|item| (Reverse(item.value), item.owner));
+
+    items
+}
+
+fn lower_bound(elves: &[Elf], items: &[Item]) -> u32 {
+    let total: u32 = items.iter().map(|item| item.value).sum();
+    let largest = items.first().map_or(0, |item| item.value);
+
+    match elves.len() {
+        0 => 0,
+        count => total.div_ceil(count as u32).max(largest),
+    }
+}
+
+fn greedy_bins(items: &[Item], bin_count: usize) -> Vec<usize> {
+    let mut loads: BinaryHeap<Reverse<(u32, usize)>> =
+        (0..bin_count).map(|bin| Reverse((0, bin))).collect();
+
+    items
+        .iter()
+        .map(|item| {
+            let Reverse((load, bin)) = loads.pop().unwrap();
+            loads.push(Reverse((load + item.value, bin)));
+
+            bin
+        })
+        .collect()
+}
+
+fn max_bin_load(items: &[Item], bins: &[usize], bin_count: usize) -> u32 {
+    let mut loads = vec![0; bin_count];
+    for (item, &bin) in items.iter().zip(bins) {
+        loads[bin] += item.value;
+    }
+
+    loads.into_iter().max().unwrap_or(0)
+}
+
+struct Search<'a> {
+    items: &'a [Item],
+    lower_bound: u32,
+    loads: Vec<u32>,
+    current: Vec<usize>,
+    best: Vec<usize>,
+    best_load: u32,
+}
+
+impl Search<'_> {
+    fn run(&mut self, next: usize, max_load: u32) {
+        if self.best_load == self.lower_bound {
+            return;
+        }
+
+        if next == self.items.len() {
+            if max_load < self.best_load {
+                self.best_load = max_load;
+                self.best = self.current.clone();
+            }
+            return;
+        }
+
+        let value = self.items[next].value;
+        let mut tried = vec![];
+
+        for bin in 0..self.loads.len() {
+            let load = self.loads[bin] + value;
+
+            if load >= self.best_load || tried.contains(&self.loads[bin]) {
+                continue;
+            }
+            tried.push(self.loads[bin]);
+
+            self.loads[bin] = load;
+            self.current[next] = bin;
+            self.run(next + 1, max_load.max(load));
+            self.loads[bin] -= value;
+        }
+    }
+}
+
+fn exact_bins(items: &[Item], bin_count: usize, lower_bound: u32) -> Vec<usize> {
+    let initial = greedy_bins(items, bin_count);
+    let mut search = Search {
+        items,
+        lower_bound,
+        loads: vec![0; bin_count],
+        current: vec![0; items.len()],
+        best_load: max_bin_load(items, &initial, bin_count),
+        best: initial,
+    };
+    search.run(0, 0);
+
+    search.best
+}
+
+fn bin_owners(items: &[Item], bins: &[usize], bin_count: usize) -> Vec<usize> {
+    let mut overlaps: HashMap<(usize, usize), u32> = HashMap::new();
+    for (item, &bin) in items.iter().zip(bins) {
+        *overlaps.entry((bin, item.owner)).or_default() += item.value;
+    }
+
+    let mut overlaps: Vec<((usize, usize), u32)> = overlaps.into_iter().collect();
+    overlaps.sort_by_key(|&((bin, owner), kept)| (Reverse(kept), bin, owner));
+
+    let mut owners = vec![None; bin_count];
+    let mut taken = vec![false; bin_count];
+
+    for ((bin, owner), _) in overlaps {
+        if owners[bin].is_none() && !taken[owner] {
+            owners[bin] = Some(owner);
+            taken[owner] = true;
+        }
+    }
+
+    let mut free = (0..bin_count).filter(|&owner| !taken[owner]);
+
+    owners
+        .into_iter()
+        .map(|owner| owner.unwrap_or_else(|| free.next().unwrap()))
+        .collect()
+}
+
+fn build_plan(
+    elves: &[Elf],
+    strategy: Strategy,
+    bins: impl Fn(&[Item], u32) -> Vec<usize>,
+) -> Plan {
+    let items = sorted_items(elves);
+    let lower_bound = lower_bound(elves, &items);
+    let bins = bins(&items, lower_bound);
+    let owners = bin_owners(&items, &bins, elves.len());
+
+    let mut loads: BTreeMap<usize, u32> = elves.iter().map(|elf| (elf.index, 0)).collect();
+    let mut moves = vec![];
+
+    for (item, bin) in items.iter().zip(bins) {
+        let target = owners[bin];
+        *loads.get_mut(&elves[target].index).unwrap() += item.value;
+
+        if target != item.owner {
+            moves.push(Move {
+                item: item.value,
+                from: elves[item.owner].index,
+                to: elves[target].index,
+            });
+        }
+    }
+    moves.sort_by_key(|step| (step.from, step.to, Reverse(step.item)));
+
+    Plan {
+        strategy,
+        loads,
+        moves,
+        lower_bound,
+    }
+}
+
+pub fn rebalance_exact(elves: &[Elf]) -> Plan {
+    build_plan(elves, Strategy::Exact, |items, lower_bound| {
+        exact_bins(items, elves.len(), lower_bound)
+    })
+}
+
+pub fn rebalance_greedy(elves: &[Elf]) -> Plan {
+    build_plan(elves, Strategy::Greedy, |items, _| {
+        greedy_bins(items, elves.len())
+    })
+}
+
+pub fn rebalance(elves: &[Elf]) -> Plan {
+    let item_count: usize = elves.iter().map(|elf| elf.item_count()).sum();
+
+    match item_count <= EXACT_ITEM_LIMIT {
+        true => rebalance_exact(elves),
+        false => rebalance_greedy(elves),
+    }
+}
+
+pub fn rebalance_under_cap(elves: &[Elf], cap: u32) -> Result<Plan, RebalanceError> {
+    if let Some((elf, item)) = elves
+        .iter()
+        .flat_map(|elf| elf.items.iter().map(move |&item| (elf, item)))
+        .find(|(_, item)| *item > cap)
+    {
+        return Err(RebalanceError::ItemTooLarge {
+            elf: elf.index,
+            item,
+            cap,
+        });
+    }
+
+    let unchanged = build_plan(elves, Strategy::Unchanged, |items, _| {
+        items.iter().map(|item| item.owner).collect()
+    });
+    if unchanged.max_load() <= cap {
+        return Ok(unchanged);
+    }
+
+    let plan = rebalance(elves);
+
+    let best = plan.max_load();
+
+    match (best <= cap, plan.strategy) {
+        (true, _) => Ok(plan),
+        (false, Strategy::Greedy) => Err(RebalanceError::NoPlanFound { cap, best }),
+        (false, _) => Err(RebalanceError::Infeasible { cap, best }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_01::parse_elves;
+
+    fn elves(inventories: &[&[u32]]) -> Vec<Elf> {
+        inventories
+            .iter()
+            .enumerate()
+            .map(|(index, items)| Elf::new(index, items.to_vec()))
+            .collect()
+    }
+
+    fn apply(elves: &[Elf], moves: &[Move]) -> BTreeMap<usize, u32> {
+        let mut loads: BTreeMap<usize, u32> =
+            elves.iter().map(|elf| (elf.index, elf.total())).collect();
+        for step in moves {
+            *loads.get_mut(&step.from).unwrap() -= step.item;
+            *loads.get_mut(&step.to).unwrap() += step.item;
+        }
+
+        loads
+    }
+
+    #[test]
+    fn balances_example_exactly() {
+        let elves = parse_elves(include_str!("examples/example.txt")).unwrap();
+        let plan = rebalance(&elves);
+
+        assert_eq!(plan.strategy, Strategy::Exact);
+        assert!(plan.loads.values().all(|load| *load == 11000));
+        assert_eq!((plan.lower_bound, plan.imbalance()), (11000, 0));
+        assert_eq!(apply(&elves, &plan.moves), plan.loads);
+        assert!(plan.moves.len() <= 6);
+    }
+
+    #[test]
+    fn beats_greedy_with_exact_search() {
+        let elves = elves(&[&[3, 3], &[2, 2, 2]]);
+        let (exact, greedy) = (rebalance_exact(&elves), rebalance_greedy(&elves));
+
+        assert_eq!((exact.max_load(), exact.moves.len()), (6, 0));
+        assert_eq!(greedy.max_load(), 7);
+        assert_eq!(apply(&elves, &greedy.moves), greedy.loads);
+    }
+
+    #[test]
+    fn falls_back_to_greedy_for_large_inputs() {
+        let items: Vec<u32> = (1..=40).map(|item| item * 100).collect();
+        let elves = elves(&[&items[..25], &items[25..], &[], &[]]);
+        let plan = rebalance(&elves);
+
+        assert_eq!(plan.strategy, Strategy::Greedy);
+        assert_eq!(plan.lower_bound, 20500);
+        assert!(plan.max_load() * 3 <= plan.lower_bound * 4);
+        assert_eq!(apply(&elves, &plan.moves), plan.loads);
+        assert_eq!(plan.loads.values().sum::<u32>(), 82000);
+    }
+
+    #[test]
+    fn respects_calorie_cap() {
+        let elves = elves(&[&[5, 4], &[1], &[]]);
+
+        let plan = rebalance_under_cap(&elves, 9).unwrap();
+        assert_eq!((plan.strategy, plan.moves.len()), (Strategy::Unchanged, 0));
+
+        let plan = rebalance_under_cap(&elves, 6).unwrap();
+        assert_eq!(plan.loads, BTreeMap::from([(0, 5), (1, 1), (2, 4)]));
+        assert_eq!(
+            plan.moves,
+            vec![Move {
+                item: 4,
+                from: 0,
+                to: 2
+            }]
+        );
+        assert_eq!(plan.moves[0].to_string(), "move 4 from elf 1 to elf 3");
+
+        assert_eq!(
+            rebalance_under_cap(&elves, 4),
+            Err(RebalanceError::ItemTooLarge {
+                elf: 0,
+                item: 5,
+                cap: 4
+            })
+        );
+        assert_eq!(
+            rebalance_under_cap(&elves[..1], 5).unwrap_err().to_string(),
+            "could not bring every elf under 5 calories (best found 9)"
+        );
+    }
+
+    #[test]
+    fn distinguishes_heuristic_misses() {
+        let mut items = vec![3; 6];
+        items.extend([2; 9]);
+        let elves = elves(&[&items, &[], &[], &[], &[], &[]]);
+
+        assert_eq!(
+            rebalance_under_cap(&elves, 6),
+            Err(RebalanceError::NoPlanFound { cap: 6, best: 7 })
+        );
+        assert_eq!(rebalance_under_cap(&elves, 7).unwrap().max_load(), 7);
+    }
+
+    #[test]
+    fn formats_plan() {
+        let elves = elves(&[&[3, 3, 2], &[], &[2]]);
+        let plan = rebalance(&elves);
+
+        assert_eq!(
+            plan.to_string(),
+            [
+                "strategy     exact search",
+                "max load                4",
+                "lower bound             4",
+                "imbalance               1",
+                "moves                   2",
+                "  move 3 from elf 1 to elf 2",
+                "  move 2 from elf 1 to elf 3",
+            ]
+            .join("\n")
+        );
+        assert_eq!(rebalance(&[]).max_load(), 0);
+
+        let plan = rebalance(&elves[1..2]).to_string();
+        assert_eq!(plan.lines().last(), Some("moves                   0"));
+        assert!(!plan.ends_with('\n'));
+    }
+
+    #[test]
+    fn keys_loads_by_elf_index() {
+        let elves = vec![Elf::new(5, vec![5, 4]), Elf::new(7, vec![1])];
+        let plan = rebalance(&elves);
+
+        assert_eq!(plan.loads, BTreeMap::from([(5, 5), (7, 5)]));
+        assert_eq!(
+            plan.moves,
+            vec![Move {
+                item: 4,
+                from: 5,
+                to: 7
+            }]
+        );
+        assert_eq!(apply(&elves, &plan.moves), plan.loads);
+    }
+}
@@ -0,0 +1,300 @@
+use std::ops::RangeInclusive;
+
+use super::Elf;
+
+const HISTOGRAM_WIDTH: usize = 40;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieStats {
+    pub count: usize,
+    pub min: u32,
+    pub max: u32,
+    pub mean: f64,
+    pub median: f64,
+    pub standard_deviation: f64,
+    pub first_quartile: f64,
+    pub third_quartile: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    pub range: RangeInclusive<u32>,
+    pub count: usize,
+}
+
+fn sorted_totals(elves: &[Elf]) -> Vec<u32> {
+    let mut totals: Vec<u32> = elves.iter().map(|elf| elf.total()).collect();
+    totals.sort_unstable();
+
+    totals
+}
+
+pub fn percentile(sorted: &[u32], percentile: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = percentile.clamp(0.0, 100.0) / 100.0 * last as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    let fraction = rank - lower as f64;
+
+    Some(sorted[lower] as f64 + (sorted[upper] as f64 - sorted[lower] as f64) * fraction)
+}
+
+impl CalorieStats {
+    pub fn new(elves: &[Elf]) -> Option<Self> {
+        let totals = sorted_totals(elves);
+        let count = totals.len();
+        let mean = totals.iter().map(|total| *total as f64).sum::<f64>() / count as f64;
+        let variance = totals
+            .iter()
+            .map(|total| (*total as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        Some(Self {
+            count,
+            min: *totals.first()?,
+            max: *totals.last()?,
+            mean,
+            median: percentile(&totals, 50.0)?,
+            standard_deviation: variance.sqrt(),
+            first_quartile: percentile(&totals, 25.0)?,
+            third_quartile: percentile(&totals, 75.0)?,
+        })
+    }
+
+    pub fn interquartile_range(&self) -> f64 {
+        self.third_quartile - self.first_quartile
+    }
+
+    pub fn is_outlier(&self, total: u32) -> bool {
+        let fence = 1.5 * self.interquartile_range();
+        let total = total as f64;
+
+        total < self.first_quartile - fence || total > self.third_quartile + fence
+    }
+}
+
+pub fn outliers(elves: &[Elf]) -> Vec<&Elf> {
+    match CalorieStats::new(elves) {
+        Some(stats) => elves
+            .iter()
+            .filter(|elf| stats.is_outlier(elf.total()))
+            .collect(),
+        None => vec![],
+    }
+}
+
+pub fn histogram(elves: &[Elf], buckets: usize) -> Vec<Bucket> {
+    let totals = sorted_totals(elves);
+    let (Some(min), Some(max)) = (totals.first(), totals.last()) else {
+        return vec![];
+    };
+    let buckets = buckets.max(1) as u64;
+    let values = (max - min) as u64 + 1;
+    let start = |bucket: u64| *min as u64 + (bucket * values).div_ceil(buckets);
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map(|bucket| {
+            let (start, end) = (start(bucket), start(bucket + 1) - 1);
+
+            Bucket {
+                range: match start <= end {
+                    true => start as u32..=end as u32,
+                    false => RangeInclusive::new(1, 0),
+                },
+                count: 0,
+            }
+        })
+        .collect();
+
+    for total in totals.iter() {
+        let bucket = (total - min) as u64 * buckets / values;
+        histogram[bucket as usize].count += 1;
+    }
+
+    histogram
+}
+
+pub fn statistics_table(elves: &[Elf], buckets: usize) -> String {
+    let Some(stats) = CalorieStats::new(elves) else {
+        return "no elves".to_string();
+    };
+    let totals = sorted_totals(elves);
+    let outliers: Vec<String> = outliers(elves)
+        .iter()
+        .map(|elf| format!("elf {} ({})", elf.number(), elf.total()))
+        .collect();
+
+    let mut rows = vec![
+        ("count".to_string(), stats.count.to_string()),
+        ("min".to_string(), stats.min.to_string()),
+        ("max".to_string(), stats.max.to_string()),
+        ("mean".to_string(), format!("{:.1}", stats.mean)),
+        ("median".to_string(), format!("{:.1}", stats.median)),
+        (
+            "std dev".to_string(),
+            format!("{:.1}", stats.standard_deviation),
+        ),
+    ];
+
+    for percentile_rank in [25.0, 75.0, 90.0, 99.0] {
+        rows.push((
+            format!("p{percentile_rank}"),
+            format!("{:.1}", percentile(&totals, percentile_rank).unwrap()),
+        ));
+    }
+
+    rows.push(("outliers".to_string(), outliers.len().to_string()));
+
+    let label_width = rows.iter().map(|(label, _)| label.len()).max().unwrap();
+    let value_width = rows.iter().map(|(_, value)| value.len()).max().unwrap();
+    let mut lines: Vec<String> = rows
+        .iter()
+        .map(|(label, value)| format!("{label:<label_width$}  {value:>value_width$}"))
+        .collect();
+
+    for outlier in outliers {
+        lines.push(format!("  {outlier}"));
+    }
+
+    let histogram = histogram(elves, buckets);
+    let largest = histogram
+        .iter()
+        .map(|bucket| bucket.count)
+        .max()
+        .unwrap_or(0);
+    let ranges: Vec<String> = histogram
+        .iter()
+        .map(|bucket| match bucket.range.is_empty() {
+            true => "-".to_string(),
+            false => format!("{}-{}", bucket.range.start(), bucket.range.end()),
+        })
+        .collect();
+    let range_width = ranges.iter().map(|range| range.len()).max().unwrap_or(0);
+
+    lines.push(String::new());
+    lines.push("histogram".to_string());
+
+    for (bucket, range) in histogram.iter().zip(ranges) {
+        let bar = "#".repeat((bucket.count * HISTOGRAM_WIDTH).div_ceil(largest.max(1)));
+        lines.push(format!("  {range:>range_width$}  {bar} {}", bucket.count));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_01::parse_elves;
+
+    fn example_elves() -> Vec<Elf> {
+        parse_elves(include_str!("examples/example.txt")).unwrap()
+    }
+
+    #[test]
+    fn computes_descriptive_statistics() {
+        let stats = CalorieStats::new(&example_elves()).unwrap();
+
+        assert_eq!((stats.count, stats.min, stats.max), (5, 4000, 24000));
+        assert_eq!((stats.mean, stats.median), (11000.0, 10000.0));
+        assert_eq!(
+            (stats.first_quartile, stats.third_quartile),
+            (6000.0, 11000.0)
+        );
+        assert!((stats.standard_deviation - 6985.7).abs() < 0.1);
+
+        assert_eq!(CalorieStats::new(&[]), None);
+    }
+
+    #[test]
+    fn interpolates_percentiles() {
+        let totals = [4000, 6000, 10000, 11000, 24000];
+
+        assert_eq!(percentile(&totals, 0.0), Some(4000.0));
+        assert_eq!(percentile(&totals, 90.0), Some(18800.0));
+        assert_eq!(percentile(&totals, 100.0), Some(24000.0));
+        assert_eq!(percentile(&[7], 50.0), Some(7.0));
+        assert_eq!(percentile(&[], 50.0), None);
+    }
+
+    #[test]
+    fn detects_outliers() {
+        let elves = example_elves();
+        let outliers: Vec<usize> = outliers(&elves).iter().map(|elf| elf.index).collect();
+
+        assert_eq!(outliers, vec![3]);
+    }
+
+    #[test]
+    fn buckets_totals() {
+        let counts: Vec<(u32, usize)> = histogram(&example_elves(), 5)
+            .iter()
+            .map(|bucket| (*bucket.range.start(), bucket.count))
+            .collect();
+
+        assert_eq!(
+            counts,
+            vec![(4000, 2), (8001, 2), (12001, 0), (16001, 0), (20001, 1)]
+        );
+
+        let elves: Vec<Elf> = [0, 3, 7, 10]
+            .into_iter()
+            .enumerate()
+            .map(|(index, total)| Elf::new(index, vec![total]))
+            .collect();
+        let ranges: Vec<(u32, u32)> = histogram(&elves, 8)
+            .iter()
+            .map(|bucket| (*bucket.range.start(), *bucket.range.end()))
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (0, 1),
+                (2, 2),
+                (3, 4),
+                (5, 5),
+                (6, 6),
+                (7, 8),
+                (9, 9),
+                (10, 10)
+            ]
+        );
+
+        for buckets in 1..=12 {
+            assert_eq!(histogram(&elves, buckets).len(), buckets);
+        }
+
+        let single = histogram(&[Elf::new(0, vec![5])], 3);
+        assert_eq!(single.len(), 3);
+        assert_eq!((single[0].range.clone(), single[0].count), (5..=5, 1));
+        assert!(single[1..].iter().all(|bucket| bucket.range.is_empty()));
+
+        let largest = histogram(&[Elf::new(0, vec![u32::MAX])], 2);
+        assert_eq!(largest[0].range, u32::MAX..=u32::MAX);
+        assert!(largest[1].range.is_empty());
+    }
+
+    #[test]
+    fn prints_statistics_table() {
+        let expected = [
+            "count           5",
+            "min          4000",
+            "max         24000",
+            "mean      11000.0",
+            "median    10000.0",
+            "std dev    6985.7",
+            "p25        6000.0",
+            "p75       11000.0",
+            "p90       18800.0",
+            "p99       23480.0",
+            "outliers        1",
+            "  elf 4 (24000)",
+            "",
+            "histogram",
+            "   4000-14000  ######################################## 4",
+            "  14001-24000  ########## 1",
+        ];
+
+        assert_eq!(statistics_table(&example_elves(), 2), expected.join("\n"));
+        assert_eq!(statistics_table(&[], 2), "no elves");
+    }
+}
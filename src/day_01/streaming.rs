@@ -0,0 +1,274 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    error, fmt,
+    io::{self, BufRead},
+};
+
+use super::elf::TOTAL_OVERFLOW;
+use crate::util::{
+    combinators::{expecting, parse_all, unsigned},
+    ParseError,
+};
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "could not read inventory: {error}"),
+            StreamError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> Self {
+        StreamError::Parse(error)
+    }
+}
+
+pub struct TopK {
+    capacity: usize,
+    heap: BinaryHeap<Reverse<u32>>,
+}
+
+impl TopK {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            heap: BinaryHeap::with_capacity(capacity + 1),
+        }
+    }
+
+    pub fn push(&mut self, value: u32) {
+        self.heap.push(Reverse(value));
+
+        if self.heap.len() > self.capacity {
+            self.heap.pop();
+        }
+    }
+
+    pub fn into_sorted_vec(self) -> Vec<u32> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(value)| value)
+            .collect()
+    }
+}
+
+struct Segments<R> {
+    reader: R,
+    line: String,
+    index: usize,
+    finished: bool,
+}
+
+struct Segment {
+    index: usize,
+    calories: Option<u32>,
+    last: bool,
+}
+
+impl<R: BufRead> Segments<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            index: 0,
+            finished: false,
+        }
+    }
+
+    fn next(&mut self) -> Result<Option<Segment>, StreamError> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        self.line.clear();
+        self.reader.read_line(&mut self.line)?;
+        self.finished = !self.line.ends_with('\n');
+
+        let index = self.index;
+        self.index += 1;
+
+        let text = self.line.strip_suffix('\n').unwrap_or(&self.line);
+        let calories = match text.is_empty() {
+            true => None,
+            false => Some(
+                parse_all(text, expecting("a calorie count", unsigned()))
+                    .map_err(|error| error.offset_lines(index))?,
+            ),
+        };
+
+        Ok(Some(Segment {
+            index,
+            calories,
+            last: self.finished,
+        }))
+    }
+}
+
+fn empty_item(index: usize) -> StreamError {
+    ParseError::new("a calorie count", "")
+        .offset_lines(index)
+        .into()
+}
+
+fn add_item(total: u32, calories: u32, index: usize) -> Result<u32, ParseError> {
+    total
+        .checked_add(calories)
+        .ok_or_else(|| ParseError::new(TOTAL_OVERFLOW, &calories.to_string()).offset_lines(index))
+}
+
+pub fn top_totals<R: BufRead>(reader: R, take: usize) -> Result<Vec<u32>, StreamError> {
+    let mut top = TopK::new(take);
+    let mut segments = Segments::new(reader);
+    let mut total: Option<Result<u32, ParseError>> = None;
+    let mut opened_blank = false;
+
+    while let Some(segment) = segments.next()? {
+        match (segment.calories, total.take()) {
+            (Some(_), None) if opened_blank => return Err(empty_item(segment.index - 1)),
+            (Some(calories), running) => {
+                total = Some(
+                    running
+                        .unwrap_or(Ok(0))
+                        .and_then(|sum| add_item(sum, calories, segment.index)),
+                );
+            }
+            (None, Some(running)) => top.push(running?),
+            (None, None) => match (opened_blank, segment.last) {
+                (false, last) => opened_blank = !last,
+                (true, true) => return Err(empty_item(segment.index - 1)),
+                (true, false) => {
+                    top.push(0);
+                    opened_blank = false;
+                }
+            },
+        }
+    }
+
+    if let Some(running) = total {
+        top.push(running?);
+    }
+
+    Ok(top.into_sorted_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        day_01::{generate_elves, parse_elves, top_elves},
+        util::Rng,
+    };
+
+    #[test]
+    fn keeps_largest_values() {
+        let mut top = TopK::new(3);
+
+        for value in [5, 1, 9, 3, 7, 9] {
+            top.push(value);
+        }
+
+        assert_eq!(top.into_sorted_vec(), vec![9, 9, 7]);
+        assert!(TopK::new(0).into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn streams_top_totals() {
+        let input = include_str!("examples/example.txt");
+
+        assert_eq!(
+            top_totals(input.as_bytes(), 3).unwrap(),
+            vec![24000, 11000, 10000]
+        );
+        assert!(top_totals("".as_bytes(), 3).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reports_line_of_invalid_calories() {
+        let error = top_totals("1000\n\n20x0\n".as_bytes(), 3).unwrap_err();
+
+        match error {
+            StreamError::Parse(error) => {
+                assert_eq!((error.line, error.token.as_str()), (3, "20x0"))
+            }
+            error => panic!("unexpected error: {error}"),
+        }
+
+        let error = top_totals("4294967295\n1\n".as_bytes(), 1).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected a calorie total that fits in 32 bits, found '1'"
+        );
+    }
+
+    #[test]
+    fn matches_parsed_elves() {
+        let mut inputs: Vec<String> = [
+            "",
+            "\n\n",
+            "1\n\n\n\n2",
+            "1\n\n\n\n",
+            "\n\n3\n4\n",
+            include_str!("examples/example.txt"),
+        ]
+        .iter()
+        .map(|input| input.to_string())
+        .collect();
+        inputs.extend((0..20).map(|seed| generate_elves(&mut Rng::new(seed), 30)));
+
+        for input in inputs {
+            let elves = parse_elves(&input).unwrap();
+            let totals: Vec<u32> = top_elves(&elves, 10)
+                .iter()
+                .map(|elf| elf.total())
+                .collect();
+
+            assert_eq!(
+                top_totals(input.as_bytes(), 10).unwrap(),
+                totals,
+                "{input:?}"
+            );
+        }
+
+        assert_eq!(top_totals("\n\n".as_bytes(), 3).unwrap(), vec![0]);
+
+        for input in [
+            "\n",
+            "1\n\n\n2",
+            "1\n\n\n",
+            "1\r\n2\r\n\r\n4",
+            "7\n\n4294967295\n1\n\n8",
+            "4294967295\n1\nx",
+            "\n5",
+        ] {
+            let expected = parse_elves(input).unwrap_err();
+
+            match top_totals(input.as_bytes(), 3) {
+                Err(StreamError::Parse(error)) => {
+                    assert_eq!(
+                        (error.line, error.expected),
+                        (expected.line, expected.expected)
+                    )
+                }
+                result => panic!("unexpected result for {input:?}: {result:?}"),
+            }
+        }
+    }
+}
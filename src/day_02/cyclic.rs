@@ -0,0 +1,283 @@
+use std::{error, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutcomeScores {
+    pub loss: usize,
+    pub draw: usize,
+    pub win: usize,
+}
+
+impl Default for OutcomeScores {
+    fn default() -> Self {
+        Self {
+            loss: 0,
+            draw: 3,
+            win: 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    InvalidMoveCount(usize),
+    DuplicateMove(String),
+    ScoreCount { expected: usize, found: usize },
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::InvalidMoveCount(count) => write!(
+                f,
+                "a cyclic game needs an odd number of at least 3 moves, found {count}"
+            ),
+            GameError::DuplicateMove(name) => write!(f, "move '{name}' appears more than once"),
+            GameError::ScoreCount { expected, found } => {
+                write!(f, "expected {expected} move scores, found {found}")
+            }
+        }
+    }
+}
+
+impl error::Error for GameError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CyclicGame {
+    moves: Vec<String>,
+    move_scores: Vec<usize>,
+    outcome_scores: OutcomeScores,
+}
+
+impl CyclicGame {
+    pub fn new(moves: &[&str]) -> Result<Self, GameError> {
+        if moves.len() < 3 || moves.len().is_multiple_of(2) {
+            return Err(GameError::InvalidMoveCount(moves.len()));
+        }
+
+        if let Some((_, name)) = moves
+            .iter()
+            .enumerate()
+            .find(|(index, name)| moves[..*index].contains(name))
+        {
+            return Err(GameError::DuplicateMove(name.to_string()));
+        }
+
+        Ok(Self {
+            moves: moves.iter().map(|name| name.to_string()).collect(),
+            move_scores: (1..=moves.len()).collect(),
+            outcome_scores: OutcomeScores::default(),
+        })
+    }
+
+    pub fn with_move_scores(self, move_scores: Vec<usize>) -> Result<Self, GameError> {
+        match move_scores.len() == self.moves.len() {
+            true => Ok(Self {
+                move_scores,
+                ..self
+            }),
+            false => Err(GameError::ScoreCount {
+                expected: self.moves.len(),
+                found: move_scores.len(),
+            }),
+        }
+    }
+
+    pub fn with_outcome_scores(self, outcome_scores: OutcomeScores) -> Self {
+        Self {
+            outcome_scores,
+            ..self
+        }
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(&["rock", "paper", "scissors"]).unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(&["rock", "spock", "paper", "lizard", "scissors"]).unwrap()
+    }
+}
+
+impl CyclicGame {
+    pub fn move_count(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn name(&self, index: usize) -> &str {
+        &self.moves[index]
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.moves.iter().position(|candidate| candidate == name)
+    }
+
+    pub fn beats(&self, index: usize) -> Vec<usize> {
+        let count = self.move_count();
+
+        (1..=count / 2)
+            .map(|distance| (index + count - distance) % count)
+            .collect()
+    }
+
+    pub fn outcome(&self, player: usize, opponent: usize) -> Outcome {
+        let count = self.move_count();
+
+        match (player + count - opponent) % count {
+            0 => Outcome::Draw,
+            distance if distance <= count / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        }
+    }
+
+    pub fn response(&self, opponent: usize, outcome: Outcome) -> usize {
+        let count = self.move_count();
+
+        match outcome {
+            Outcome::Loss => (opponent + count - 1) % count,
+            Outcome::Draw => opponent,
+            Outcome::Win => (opponent + 1) % count,
+        }
+    }
+
+    pub fn move_score(&self, index: usize) -> usize {
+        self.move_scores[index]
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> usize {
+        match outcome {
+            Outcome::Loss => self.outcome_scores.loss,
+            Outcome::Draw => self.outcome_scores.draw,
+            Outcome::Win => self.outcome_scores.win,
+        }
+    }
+
+    pub fn score(&self, player: usize, opponent: usize) -> usize {
+        self.move_score(player) + self.outcome_score(self.outcome(player, opponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seven_way() -> CyclicGame {
+        CyclicGame::new(&[
+            "rock", "water", "air", "paper", "sponge", "scissors", "fire",
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn plays_rock_paper_scissors() {
+        let game = CyclicGame::rock_paper_scissors();
+        let (rock, paper, scissors) = (0, 1, 2);
+
+        assert_eq!(game.outcome(paper, rock), Outcome::Win);
+        assert_eq!(game.outcome(rock, paper), Outcome::Loss);
+        assert_eq!(game.outcome(rock, scissors), Outcome::Win);
+        assert_eq!(game.outcome(scissors, scissors), Outcome::Draw);
+
+        assert_eq!(game.score(paper, rock), 8);
+        assert_eq!(game.score(rock, paper), 1);
+        assert_eq!(game.score(scissors, scissors), 6);
+
+        assert_eq!(game.beats(rock), vec![scissors]);
+        assert_eq!(game.beats(paper), vec![rock]);
+    }
+
+    #[test]
+    fn plays_larger_games() {
+        let game = CyclicGame::rock_paper_scissors_lizard_spock();
+        let wins_against = |player: &str, opponent: &str| {
+            game.outcome(
+                game.index_of(player).unwrap(),
+                game.index_of(opponent).unwrap(),
+            ) == Outcome::Win
+        };
+
+        assert!(wins_against("rock", "scissors") && wins_against("rock", "lizard"));
+        assert!(wins_against("spock", "scissors") && wins_against("spock", "rock"));
+        assert!(wins_against("lizard", "spock") && wins_against("lizard", "paper"));
+        assert!(!wins_against("paper", "scissors") && !wins_against("paper", "lizard"));
+
+        let game = seven_way();
+        for index in 0..game.move_count() {
+            let wins: Vec<usize> = (0..game.move_count())
+                .filter(|&other| game.outcome(index, other) == Outcome::Win)
+                .collect();
+
+            assert_eq!(wins.len(), 3);
+            assert!(game.beats(index).iter().all(|other| wins.contains(other)));
+        }
+
+        let beaten: Vec<&str> = game
+            .beats(0)
+            .into_iter()
+            .map(|other| game.name(other))
+            .collect();
+        assert_eq!(beaten, vec!["fire", "scissors", "sponge"]);
+    }
+
+    #[test]
+    fn responds_with_requested_outcome() {
+        for game in [
+            CyclicGame::rock_paper_scissors(),
+            CyclicGame::rock_paper_scissors_lizard_spock(),
+            seven_way(),
+        ] {
+            for opponent in 0..game.move_count() {
+                for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+                    let player = game.response(opponent, outcome);
+                    assert_eq!(game.outcome(player, opponent), outcome);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn configures_scores() {
+        let game = CyclicGame::rock_paper_scissors_lizard_spock()
+            .with_move_scores(vec![5, 4, 3, 2, 1])
+            .unwrap()
+            .with_outcome_scores(OutcomeScores {
+                loss: 1,
+                draw: 2,
+                win: 10,
+            });
+
+        assert_eq!(game.score(1, 0), 14);
+        assert_eq!(game.score(0, 1), 6);
+        assert_eq!(game.score(4, 4), 3);
+
+        assert_eq!(
+            CyclicGame::rock_paper_scissors().with_move_scores(vec![1, 2]),
+            Err(GameError::ScoreCount {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_games() {
+        assert_eq!(
+            CyclicGame::new(&["rock", "paper"]),
+            Err(GameError::InvalidMoveCount(2))
+        );
+        assert_eq!(
+            CyclicGame::new(&["rock"]).unwrap_err().to_string(),
+            "a cyclic game needs an odd number of at least 3 moves, found 1"
+        );
+        assert_eq!(
+            CyclicGame::new(&["rock", "paper", "rock"]),
+            Err(GameError::DuplicateMove("rock".to_string()))
+        );
+    }
+}
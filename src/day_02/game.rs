@@ -1,3 +1,10 @@
+use std::{str::FromStr, sync::LazyLock};
+
+use super::cyclic::{CyclicGame, Outcome};
+use crate::util::ParseError;
+
+static ROCK_PAPER_SCISSORS: LazyLock<CyclicGame> = LazyLock::new(CyclicGame::rock_paper_scissors);
+
 #[derive(Debug, Clone)]
 pub enum GameMove {
     Rock,
@@ -5,43 +12,54 @@ pub enum GameMove {
     Scissors,
 }
 
-impl GameMove {
-    pub fn from_string(input: &str) -> Self {
+impl FromStr for GameMove {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
-            "A" | "X" => Self::Rock,
-            "B" | "Y" => Self::Paper,
-            "C" | "Z" | _ => Self::Scissors,
+            "A" | "X" => Ok(Self::Rock),
+            "B" | "Y" => Ok(Self::Paper),
+            "C" | "Z" => Ok(Self::Scissors),
+            _ => Err(ParseError::new("one of A, B, C, X, Y or Z", input)),
         }
     }
+}
 
-    pub fn from_string_with_result(opponent_move: &GameMove, input: &str) -> Self {
-        match input {
-            // lose
-            "X" => match opponent_move {
-                GameMove::Rock => GameMove::Scissors,
-                GameMove::Paper => GameMove::Rock,
-                GameMove::Scissors => GameMove::Paper,
-            },
-            // draw
-            "Y" => opponent_move.clone(),
-            // win
-            "Z" | _ => match opponent_move {
-                GameMove::Rock => GameMove::Paper,
-                GameMove::Paper => GameMove::Scissors,
-                GameMove::Scissors => GameMove::Rock,
-            },
+impl GameMove {
+    pub fn from_str_with_result(opponent_move: &GameMove, input: &str) -> Result<Self, ParseError> {
+        let outcome = match input {
+            "X" => Outcome::Loss,
+            "Y" => Outcome::Draw,
+            "Z" => Outcome::Win,
+            _ => return Err(ParseError::new("one of X, Y or Z", input)),
+        };
+
+        Ok(Self::from_index(
+            ROCK_PAPER_SCISSORS.response(opponent_move.index(), outcome),
+        ))
+    }
+
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => GameMove::Rock,
+            1 => GameMove::Paper,
+            _ => GameMove::Scissors,
         }
     }
 }
 
 impl GameMove {
-    pub fn score(&self) -> usize {
+    pub fn index(&self) -> usize {
         match self {
-            GameMove::Rock => 1,
-            GameMove::Paper => 2,
-            GameMove::Scissors => 3,
+            GameMove::Rock => 0,
+            GameMove::Paper => 1,
+            GameMove::Scissors => 2,
         }
     }
+
+    pub fn score(&self) -> usize {
+        ROCK_PAPER_SCISSORS.move_score(self.index())
+    }
 }
 
 pub struct Game {
@@ -49,28 +67,48 @@ pub struct Game {
     opponent_move: GameMove,
 }
 
-impl Game {
-    pub fn from_string(input: &str) -> Self {
-        let mut components = input.split(" ");
+fn split_moves(input: &str) -> Result<(&str, &str), ParseError> {
+    let mut components = input.split(' ');
 
-        Self {
-            opponent_move: GameMove::from_string(components.nth(0).unwrap()),
-            player_move: GameMove::from_string(components.nth(0).unwrap()),
-        }
+    match (components.next(), components.next(), components.next()) {
+        (Some(opponent), Some(player), None) => Ok((opponent, player)),
+        _ => Err(ParseError::new("two moves separated by a space", input)),
     }
+}
+
+fn parse_move<T>(
+    input: &str,
+    token: &str,
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    parse(token).map_err(|error| ParseError::in_line(input, token, &error.expected))
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (opponent, player) = split_moves(input)?;
 
-    pub fn from_string_with_result(input: &str) -> Self {
-        let mut components = input.split(" ");
+        Ok(Self {
+            opponent_move: parse_move(input, opponent, GameMove::from_str)?,
+            player_move: parse_move(input, player, GameMove::from_str)?,
+        })
+    }
+}
+
+impl Game {
+    pub fn from_str_with_result(input: &str) -> Result<Self, ParseError> {
+        let (opponent, player) = split_moves(input)?;
 
-        let opponent_move = GameMove::from_string(components.nth(0).unwrap());
+        let opponent_move = parse_move(input, opponent, GameMove::from_str)?;
 
-        Self {
-            player_move: GameMove::from_string_with_result(
-                &opponent_move,
-                components.nth(0).unwrap(),
-            ),
+        Ok(Self {
+            player_move: parse_move(input, player, |token| {
+                GameMove::from_str_with_result(&opponent_move, token)
+            })?,
             opponent_move,
-        }
+        })
     }
 }
 
@@ -80,23 +118,9 @@ impl Game {
     }
 
     pub fn evaluate(&self) -> usize {
-        match self.player_move {
-            GameMove::Rock => match self.opponent_move {
-                GameMove::Rock => 3,
-                GameMove::Paper => 0,
-                GameMove::Scissors => 6,
-            },
-            GameMove::Paper => match self.opponent_move {
-                GameMove::Rock => 6,
-                GameMove::Paper => 3,
-                GameMove::Scissors => 0,
-            },
-            GameMove::Scissors => match self.opponent_move {
-                GameMove::Rock => 0,
-                GameMove::Paper => 6,
-                GameMove::Scissors => 3,
-            },
-        }
+        ROCK_PAPER_SCISSORS.outcome_score(
+            ROCK_PAPER_SCISSORS.outcome(self.player_move.index(), self.opponent_move.index()),
+        )
     }
 }
 
@@ -104,18 +128,32 @@ impl Game {
 mod tests {
     use super::Game;
 
+    fn score(input: &str) -> usize {
+        input.parse::<Game>().unwrap().score()
+    }
+
     #[test]
     fn calculates_game_score() {
-        assert_eq!(Game::from_string("A X").score(), 4);
-        assert_eq!(Game::from_string("A Y").score(), 8);
-        assert_eq!(Game::from_string("A Z").score(), 3);
+        assert_eq!(score("A X"), 4);
+        assert_eq!(score("A Y"), 8);
+        assert_eq!(score("A Z"), 3);
 
-        assert_eq!(Game::from_string("B X").score(), 1);
-        assert_eq!(Game::from_string("B Y").score(), 5);
-        assert_eq!(Game::from_string("B Z").score(), 9);
+        assert_eq!(score("B X"), 1);
+        assert_eq!(score("B Y"), 5);
+        assert_eq!(score("B Z"), 9);
+
+        assert_eq!(score("C X"), 7);
+        assert_eq!(score("C Y"), 2);
+        assert_eq!(score("C Z"), 6);
+    }
+
+    #[test]
+    fn rejects_unknown_moves() {
+        let error = "A Q".parse::<Game>().err().unwrap();
+        assert_eq!(error.column, 3);
+        assert_eq!(error.token, "Q");
 
-        assert_eq!(Game::from_string("C X").score(), 7);
-        assert_eq!(Game::from_string("C Y").score(), 2);
-        assert_eq!(Game::from_string("C Z").score(), 6);
+        assert!(Game::from_str_with_result("B A").is_err());
+        assert!("A".parse::<Game>().is_err());
     }
 }
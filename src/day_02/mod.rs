@@ -1,32 +1,62 @@
+mod cyclic;
 mod game;
 
-use crate::util::read_input;
-use game::Game;
+use crate::{
+    solver::{Answer, Solver},
+    util::{parse_lines, ParseError, Rng},
+};
+pub use cyclic::{CyclicGame, GameError, Outcome, OutcomeScores};
+pub use game::{Game, GameMove};
 
-fn find_win_score(input: &str) -> usize {
+fn parse_games_given_result(input: &str) -> Result<Vec<Game>, ParseError> {
     input
         .lines()
-        .map(|line| Game::from_string(line).score())
-        .sum()
+        .enumerate()
+        .map(|(index, line)| {
+            Game::from_str_with_result(line).map_err(|error| error.offset_lines(index))
+        })
+        .collect()
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_02/input.txt");
-
-    find_win_score(&input)
+fn generate_strategy_guide(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.pick(&["A", "B", "C"]),
+                rng.pick(&["X", "Y", "Z"])
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-fn find_win_score_given_result(input: &str) -> usize {
-    input
-        .lines()
-        .map(|line| Game::from_string_with_result(line).score())
-        .sum()
+fn find_win_score(games: &[Game]) -> usize {
+    games.iter().map(|game| game.score()).sum()
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_02/input.txt");
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = (Vec<Game>, Vec<Game>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((parse_lines(input)?, parse_games_given_result(input)?))
+    }
+
+    fn solve_part_1((games, _): &Self::Input) -> Answer {
+        find_win_score(games).into()
+    }
 
-    find_win_score_given_result(&input)
+    fn solve_part_2((_, games_given_result): &Self::Input) -> Answer {
+        find_win_score(games_given_result).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_strategy_guide(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -37,7 +67,7 @@ mod test {
     fn finds_win_score() {
         let input = "A Y\nB X\nC Z";
 
-        let result = find_win_score(input);
+        let result = find_win_score(&parse_lines(input).unwrap());
         assert_eq!(result, 15);
     }
 
@@ -45,7 +75,7 @@ mod test {
     fn finds_win_score_given_result() {
         let input = "A Y\nB X\nC Z";
 
-        let result = find_win_score_given_result(input);
+        let result = find_win_score(&parse_games_given_result(input).unwrap());
         assert_eq!(result, 12);
     }
 }
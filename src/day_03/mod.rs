@@ -1,10 +1,13 @@
-use crate::util::{read_input, split_string};
+use crate::{
+    solver::{Answer, Solver},
+    util::{split_string, ParseError, Rng},
+};
 
 fn find_group_badge(input: &str) -> char {
     let mut lines = input.lines();
-    let first_line = lines.nth(0).unwrap();
-    let second_line = lines.nth(0).unwrap();
-    let third_line = lines.nth(0).unwrap();
+    let first_line = lines.next().unwrap();
+    let second_line = lines.next().unwrap();
+    let third_line = lines.next().unwrap();
 
     first_line
         .chars()
@@ -38,20 +41,14 @@ fn item_priority(c: &char) -> usize {
 fn duplicate_item_priority(input: &str) -> usize {
     let duplicated = find_compartment_duplicates(input);
 
-    duplicated.iter().map(|c| item_priority(c)).sum()
+    duplicated.iter().map(item_priority).sum()
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_03/input.txt");
-
-    input
-        .lines()
-        .map(|line| duplicate_item_priority(line))
-        .sum()
+fn sum_duplicate_item_priorities(input: &str) -> usize {
+    input.lines().map(duplicate_item_priority).sum()
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_03/input.txt");
+fn sum_group_badge_priorities(input: &str) -> usize {
     let mut lines = input.lines();
     let mut sum = 0;
 
@@ -65,6 +62,55 @@ pub fn solve_part_2() -> usize {
     sum
 }
 
+fn generate_rucksacks(rng: &mut Rng, size: usize) -> String {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut lines = vec![];
+
+    for _ in 0..size {
+        rng.shuffle(&mut items);
+        let badge = items[0];
+
+        for pool in items[1..].chunks(17) {
+            let fillers = 1 + rng.below(8);
+            let mut first: Vec<char> = pool[..=fillers].to_vec();
+            let mut second: Vec<char> = [pool[0], badge]
+                .into_iter()
+                .chain(pool[fillers + 1..2 * fillers].iter().copied())
+                .collect();
+
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+            lines.push(first.into_iter().chain(second).collect::<String>());
+        }
+    }
+
+    lines.join("\n")
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn solve_part_1(input: &Self::Input) -> Answer {
+        sum_duplicate_item_priorities(input).into()
+    }
+
+    fn solve_part_2(input: &Self::Input) -> Answer {
+        sum_group_badge_priorities(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_rucksacks(rng, size))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,7 +1,15 @@
-use crate::util::read_input;
+use std::str::FromStr;
+
+use crate::{
+    solver::{Answer, Solver},
+    util::{
+        combinators::{expecting, map, pair, parse_all, unsigned, Parser},
+        parse_lines, ParseError, Rng,
+    },
+};
 
 #[derive(Debug)]
-struct Range {
+pub struct Range {
     lower: u32,
     upper: u32,
 }
@@ -24,22 +32,48 @@ impl Range {
     }
 }
 
-fn decompose_range(input: &str) -> Range {
-    let result: Vec<&str> = input.split('-').collect();
+fn range<'a>() -> impl Parser<'a, Range> {
+    let bound = || expecting("a section number", unsigned());
+
+    map(pair(bound(), "-", bound()), |(lower, upper)| Range {
+        lower,
+        upper,
+    })
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_all(input, range())
+    }
+}
+
+pub struct RangePair(Range, Range);
+
+impl FromStr for RangePair {
+    type Err = ParseError;
 
-    Range {
-        lower: result.get(0).unwrap().parse().unwrap(),
-        upper: result.get(1).unwrap().parse().unwrap(),
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_all(
+            input,
+            map(pair(range(), ",", range()), |(first, second)| {
+                RangePair(first, second)
+            }),
+        )
     }
 }
 
-fn find_ranges(input: &str) -> (Range, Range) {
-    let result: Vec<&str> = input.split(',').collect();
+fn generate_range_pairs(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let lower = rng.range(1..=99);
+        format!("{lower}-{}", rng.range(lower..=99))
+    };
 
-    (
-        decompose_range(result.get(0).unwrap()),
-        decompose_range(result.get(1).unwrap()),
-    )
+    (0..size)
+        .map(|_| format!("{},{}", range(), range()))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 fn is_full_overlap(r1: &Range, r2: &Range) -> bool {
@@ -50,40 +84,46 @@ fn is_partial_overlap(r1: &Range, r2: &Range) -> bool {
     r1.partially_overlaps(r2) || r2.partially_overlaps(r1)
 }
 
-fn ranges_fully_overlap(input: &str) -> Option<()> {
-    let (r1, r2) = find_ranges(input);
-
-    match is_full_overlap(&r1, &r2) {
+fn ranges_fully_overlap(RangePair(r1, r2): &RangePair) -> Option<()> {
+    match is_full_overlap(r1, r2) {
         true => Some(()),
         false => None,
     }
 }
 
-fn ranges_partially_overlap(input: &str) -> Option<()> {
-    let (r1, r2) = find_ranges(input);
-
-    match is_partial_overlap(&r1, &r2) {
+fn ranges_partially_overlap(RangePair(r1, r2): &RangePair) -> Option<()> {
+    match is_partial_overlap(r1, r2) {
         true => Some(()),
         false => None,
     }
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_04/input.txt");
+pub struct Day04;
 
-    input
-        .lines()
-        .filter_map(|line| ranges_fully_overlap(line))
-        .count()
-}
+impl Solver for Day04 {
+    const DAY: u8 = 4;
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_04/input.txt");
+    type Input = Vec<RangePair>;
 
-    input
-        .lines()
-        .filter_map(|line| ranges_partially_overlap(line))
-        .count()
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn solve_part_1(pairs: &Self::Input) -> Answer {
+        pairs.iter().filter_map(ranges_fully_overlap).count().into()
+    }
+
+    fn solve_part_2(pairs: &Self::Input) -> Answer {
+        pairs
+            .iter()
+            .filter_map(ranges_partially_overlap)
+            .count()
+            .into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_range_pairs(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -92,22 +132,31 @@ mod test {
 
     #[test]
     fn determines_full_overlap() {
-        let result = ranges_fully_overlap("6-6,4-6");
+        let result = ranges_fully_overlap(&"6-6,4-6".parse().unwrap());
         assert_eq!(result, Some(()));
 
-        let result = ranges_fully_overlap("5-7,7-9");
+        let result = ranges_fully_overlap(&"5-7,7-9".parse().unwrap());
         assert_eq!(result, None);
     }
 
     #[test]
     fn determines_partial_overlap() {
-        let result = ranges_partially_overlap("6-6,4-6");
+        let result = ranges_partially_overlap(&"6-6,4-6".parse().unwrap());
         assert_eq!(result, Some(()));
 
-        let result = ranges_partially_overlap("5-7,7-9");
+        let result = ranges_partially_overlap(&"5-7,7-9".parse().unwrap());
         assert_eq!(result, Some(()));
 
-        let result = ranges_partially_overlap("5-6,7-9");
+        let result = ranges_partially_overlap(&"5-6,7-9".parse().unwrap());
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        let error = "2-4,6-x".parse::<RangePair>().err().unwrap();
+        assert_eq!(error.column, 7);
+        assert_eq!(error.token, "x");
+
+        assert!("2-4".parse::<RangePair>().is_err());
+    }
 }
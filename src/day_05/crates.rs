@@ -1,38 +1,65 @@
-use std::{collections::HashMap, vec};
+use std::{collections::HashMap, str::FromStr, vec};
+
+use crate::util::ParseError;
 
 pub type CrateStack = Vec<char>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CrateArrangement {
     columns: HashMap<usize, CrateStack>,
 }
 
-impl CrateArrangement {
-    pub fn from_string(input: &str) -> Self {
+fn unexpected_symbol(expected: &str, symbol: char, line: usize, column: usize) -> ParseError {
+    ParseError::new(expected, &symbol.to_string())
+        .offset_lines(line)
+        .offset_columns(column)
+}
+
+impl FromStr for CrateArrangement {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
         let mut indices: Vec<(usize, usize)> = vec![];
         let mut columns: HashMap<usize, CrateStack> = HashMap::new();
 
-        let index_line = input.lines().last().unwrap();
+        let lines: Vec<&str> = input.lines().collect();
+        let index_line_number = lines.len().saturating_sub(1);
+        let index_line = lines.last().copied().unwrap_or_default();
 
         for (index, character) in index_line.chars().enumerate() {
-            if !character.is_numeric() {
+            if character.is_whitespace() {
                 continue;
             }
 
-            let column_number: usize = character.to_string().parse().unwrap();
+            let column_number = character.to_digit(10).ok_or_else(|| {
+                unexpected_symbol("a column number", character, index_line_number, index)
+            })? as usize;
 
             indices.push((index, column_number));
             columns.insert(column_number, vec![]);
         }
 
-        for line in input.lines().rev().skip(1) {
+        if indices.is_empty() {
+            return Err(ParseError::new("a line of column numbers", index_line)
+                .offset_lines(index_line_number));
+        }
+
+        for (line_number, line) in lines.iter().enumerate().rev().skip(1) {
             let line_symbols: Vec<char> = line.chars().collect();
 
             for (index, column_number) in indices.iter() {
-                let next = line_symbols.get(*index).unwrap();
+                let next = match line_symbols.get(*index) {
+                    Some(next) if !next.is_whitespace() => next,
+                    _ => continue,
+                };
 
                 if !next.is_alphabetic() {
-                    continue;
+                    return Err(unexpected_symbol(
+                        "a crate letter",
+                        *next,
+                        line_number,
+                        *index,
+                    ));
                 }
 
                 let column = columns.get_mut(column_number).unwrap();
@@ -40,7 +67,20 @@ impl CrateArrangement {
             }
         }
 
-        Self { columns }
+        Ok(Self { columns })
+    }
+}
+
+impl CrateArrangement {
+    pub fn has_column(&self, column: usize) -> bool {
+        self.columns.contains_key(&column)
+    }
+
+    pub fn heights(&self) -> HashMap<usize, usize> {
+        self.columns
+            .iter()
+            .map(|(column, stack)| (*column, stack.len()))
+            .collect()
     }
 }
 
@@ -56,6 +96,7 @@ impl CrateArrangement {
         let mut end = self.columns.get(&to).unwrap().clone();
 
         let start_boxes = start.len();
+        let count = count.min(start_boxes);
         let moved_boxes: Vec<&char> = start.iter().skip(start_boxes - count).collect();
 
         for moved in moved_boxes.iter() {
@@ -110,7 +151,7 @@ mod tests {
     fn constructs_arrangement() {
         let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ";
 
-        let arrangement = CrateArrangement::from_string(input);
+        let arrangement: CrateArrangement = input.parse().unwrap();
 
         assert_eq!(arrangement.columns.get(&1).unwrap(), &vec!['Z', 'N']);
         assert_eq!(arrangement.columns.get(&2).unwrap(), &vec!['M', 'C', 'D']);
@@ -121,7 +162,7 @@ mod tests {
     fn moves_boxes() {
         let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ";
 
-        let mut arrangement = CrateArrangement::from_string(input);
+        let mut arrangement: CrateArrangement = input.parse().unwrap();
 
         arrangement.move_boxes(2, 1, 1);
 
@@ -135,7 +176,7 @@ mod tests {
     fn moves_boxes_full_procedure() {
         let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ";
 
-        let mut arrangement = CrateArrangement::from_string(input);
+        let mut arrangement: CrateArrangement = input.parse().unwrap();
 
         arrangement.move_boxes(2, 1, 1);
         arrangement.move_boxes(1, 3, 3);
@@ -149,4 +190,24 @@ mod tests {
             &vec!['P', 'D', 'N', 'Z']
         );
     }
+
+    #[test]
+    fn moves_at_most_the_whole_stack() {
+        let mut arrangement: CrateArrangement = "[A]    \n 1   2 ".parse().unwrap();
+
+        arrangement.move_multiple_boxes(1, 2, 3);
+
+        assert_eq!(arrangement.columns.get(&1).unwrap(), &vec![]);
+        assert_eq!(arrangement.columns.get(&2).unwrap(), &vec!['A']);
+        assert_eq!(arrangement.heights(), HashMap::from([(1, 0), (2, 1)]));
+    }
+
+    #[test]
+    fn rejects_misaligned_crates() {
+        let input = "    [D]    \n[N] [C]    \n[Z][M] [P] \n 1   2   3 ";
+
+        let error = input.parse::<CrateArrangement>().unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        assert_eq!(error.token, "]");
+    }
 }
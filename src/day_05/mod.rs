@@ -1,54 +1,121 @@
 mod crates;
 
-use crate::util::read_input;
-use crates::CrateArrangement;
+use std::str::FromStr;
+
+use crate::{
+    solver::{Answer, Solver},
+    util::{
+        combinators::{parse_all, preceded, unsigned, Scanner},
+        parse_lines, ParseError, Rng,
+    },
+};
+pub use crates::{CrateArrangement, CrateStack};
 
 #[derive(Debug)]
-struct Procedure {
+pub struct Procedure {
     from: usize,
     to: usize,
     count: usize,
 }
 
-fn to_number(input: Option<&str>) -> usize {
-    input.unwrap().parse().unwrap()
+impl FromStr for Procedure {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_all(input, |scanner: &mut Scanner| {
+            Ok(Self {
+                count: preceded("move ", unsigned())(scanner)?,
+                from: preceded(" from ", unsigned())(scanner)?,
+                to: preceded(" to ", unsigned())(scanner)?,
+            })
+        })
+    }
 }
 
-impl Procedure {
-    pub fn from_string(input: &str) -> Self {
-        let formatted = input
-            .replace("move ", "")
-            .replace(" from ", ",")
-            .replace(" to ", ",");
+fn parse_input(input: &str) -> Result<(CrateArrangement, Vec<Procedure>), ParseError> {
+    let (arrangement_text, procedure_text) = input.split_once("\n\n").ok_or_else(|| {
+        let last = input.lines().last().unwrap_or_default();
+
+        ParseError::in_line(
+            last,
+            &last[last.len()..],
+            "a blank line after the crate arrangement",
+        )
+        .offset_lines(input.lines().count().saturating_sub(1))
+    })?;
+
+    let arrangement: CrateArrangement = arrangement_text.parse()?;
+
+    let line_offset = arrangement_text.lines().count() + 1;
+    let procedures: Vec<Procedure> =
+        parse_lines(procedure_text).map_err(|error| error.offset_lines(line_offset))?;
 
-        let mut components = formatted.split(',');
+    let mut heights = arrangement.heights();
 
-        Self {
-            count: to_number(components.next()),
-            from: to_number(components.next()),
-            to: to_number(components.next()),
+    for (index, (procedure, line)) in procedures.iter().zip(procedure_text.lines()).enumerate() {
+        let tokens: Vec<&str> = line.split(' ').collect();
+        let error_at = |token: &str, expected: &str| {
+            ParseError::in_line(line, token, expected).offset_lines(line_offset + index)
+        };
+
+        for (column, token) in [(procedure.from, tokens[3]), (procedure.to, tokens[5])] {
+            if !arrangement.has_column(column) {
+                return Err(error_at(token, "an existing column"));
+            }
+        }
+
+        let height = heights[&procedure.from];
+        if procedure.count > height {
+            return Err(error_at(tokens[1], &format!("a count of at most {height}")));
         }
+
+        *heights.get_mut(&procedure.from).unwrap() -= procedure.count;
+        *heights.get_mut(&procedure.to).unwrap() += procedure.count;
     }
+
+    Ok((arrangement, procedures))
 }
 
-fn parse_input(input: &str) -> (CrateArrangement, Vec<Procedure>) {
-    let mut components = input.split("\n\n");
+fn generate_crates_and_procedures(rng: &mut Rng, size: usize) -> String {
+    let columns = 2 + rng.below(8);
+    let mut heights: Vec<usize> = (0..columns).map(|_| rng.below(8)).collect();
+    let mut lines = vec![];
+
+    for level in (0..heights.iter().copied().max().unwrap_or(0)).rev() {
+        let row: Vec<String> = heights
+            .iter()
+            .map(|height| match *height > level {
+                true => format!("[{}]", (b'A' + rng.below(26) as u8) as char),
+                false => "   ".to_string(),
+            })
+            .collect();
+
+        lines.push(row.join(" "));
+    }
 
-    let arrangement_text = components.next().unwrap();
-    let procedure_text = components.next().unwrap();
+    let indices: Vec<String> = (1..=columns).map(|column| format!(" {column} ")).collect();
+    lines.push(indices.join(" "));
 
-    let arrangement = CrateArrangement::from_string(arrangement_text);
+    let mut procedures = vec![];
 
-    let procedures: Vec<Procedure> = procedure_text
-        .lines()
-        .map(|l| Procedure::from_string(l))
-        .collect();
+    for _ in 0..size {
+        let from = rng.below(columns);
+        let to = (from + 1 + rng.below(columns - 1)) % columns;
+        let count = rng.below(heights[from] + 1);
 
-    (arrangement, procedures)
+        heights[from] -= count;
+        heights[to] += count;
+        procedures.push(format!("move {count} from {} to {}", from + 1, to + 1));
+    }
+
+    format!("{}\n\n{}", lines.join("\n"), procedures.join("\n"))
 }
 
-fn determine_top_boxes_after_procedures(input: &str) -> String {
-    let (mut arrangement, procedures) = parse_input(&input);
+fn determine_top_boxes_after_procedures(
+    arrangement: &CrateArrangement,
+    procedures: &[Procedure],
+) -> String {
+    let mut arrangement = arrangement.clone();
 
     for procedure in procedures {
         arrangement.move_boxes(procedure.from, procedure.to, procedure.count);
@@ -57,14 +124,11 @@ fn determine_top_boxes_after_procedures(input: &str) -> String {
     arrangement.top_boxes()
 }
 
-pub fn solve_part_1() -> String {
-    let input = read_input("src/day_05/input.txt");
-
-    determine_top_boxes_after_procedures(&input)
-}
-
-fn determine_top_boxes_after_procedures_multiple(input: &str) -> String {
-    let (mut arrangement, procedures) = parse_input(&input);
+fn determine_top_boxes_after_procedures_multiple(
+    arrangement: &CrateArrangement,
+    procedures: &[Procedure],
+) -> String {
+    let mut arrangement = arrangement.clone();
 
     for procedure in procedures {
         arrangement.move_multiple_boxes(procedure.from, procedure.to, procedure.count);
@@ -73,10 +137,28 @@ fn determine_top_boxes_after_procedures_multiple(input: &str) -> String {
     arrangement.top_boxes()
 }
 
-pub fn solve_part_2() -> String {
-    let input = read_input("src/day_05/input.txt");
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (CrateArrangement, Vec<Procedure>);
 
-    determine_top_boxes_after_procedures_multiple(&input)
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn solve_part_1((arrangement, procedures): &Self::Input) -> Answer {
+        determine_top_boxes_after_procedures(arrangement, procedures).into()
+    }
+
+    fn solve_part_2((arrangement, procedures): &Self::Input) -> Answer {
+        determine_top_boxes_after_procedures_multiple(arrangement, procedures).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_crates_and_procedures(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -85,15 +167,66 @@ mod test {
 
     #[test]
     fn determines_final_arrangement() {
-        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
-        let result = determine_top_boxes_after_procedures(input);
+        let input = include_str!("examples/example.txt");
+        let (arrangement, procedures) = parse_input(input).unwrap();
+        let result = determine_top_boxes_after_procedures(&arrangement, &procedures);
         assert_eq!(result, "CMZ".to_string());
     }
 
     #[test]
     fn determines_final_arrangement_multiple_boxes() {
-        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
-        let result = determine_top_boxes_after_procedures_multiple(input);
+        let input = include_str!("examples/example.txt");
+        let (arrangement, procedures) = parse_input(input).unwrap();
+        let result = determine_top_boxes_after_procedures_multiple(&arrangement, &procedures);
         assert_eq!(result, "MCD".to_string());
     }
+
+    #[test]
+    fn parses_procedure() {
+        let procedure: Procedure = "move 3 from 1 to 2".parse().unwrap();
+
+        assert_eq!((procedure.count, procedure.from, procedure.to), (3, 1, 2));
+    }
+
+    #[test]
+    fn rejects_missing_separator() {
+        let error = parse_input("[A]\n 1 \nmove 1 from 1 to 1").unwrap_err();
+
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (3, 19, "")
+        );
+        assert_eq!(error.expected, "a blank line after the crate arrangement");
+    }
+
+    #[test]
+    fn rejects_invalid_procedures() {
+        let error = "move 3 frm 1 to 2".parse::<Procedure>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (8, "frm"));
+
+        let error = "move x from 1 to 2".parse::<Procedure>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (6, "x"));
+
+        let input = "[A]\n 1 \n\nmove 1 from 1 to 1\nmove 1 from 1 to 4";
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (5, 18, "4")
+        );
+
+        let input = "[A]\n 1 \n\nmove 1 from 09 to 1";
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (4, 13, "09")
+        );
+
+        let input = "[A]    \n 1   2 \n\nmove 1 from 1 to 2\nmove 2 from 2 to 1";
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (5, 6, "2")
+        );
+        assert_eq!(error.expected, "a count of at most 1");
+    }
 }
@@ -1,4 +1,7 @@
-use crate::util::read_input;
+use crate::{
+    solver::{Answer, Solver},
+    util::{ParseError, Rng},
+};
 
 fn does_symbol_repeat(line: &str) -> bool {
     let mut current = String::new();
@@ -31,16 +34,38 @@ fn find_packet_marker(input: &str, marker_size: usize) -> Option<usize> {
     None
 }
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_06/input.txt");
+fn generate_datastream(rng: &mut Rng, size: usize) -> String {
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    let mut stream: String = (0..size).map(|_| *rng.pick(&letters)).collect();
 
-    find_packet_marker(&input, 4).unwrap()
+    rng.shuffle(&mut letters);
+    stream.extend(&letters[..14]);
+
+    stream
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_06/input.txt");
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn solve_part_1(input: &Self::Input) -> Answer {
+        find_packet_marker(input, 4).unwrap().into()
+    }
 
-    find_packet_marker(&input, 14).unwrap()
+    fn solve_part_2(input: &Self::Input) -> Answer {
+        find_packet_marker(input, 14).unwrap().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_datastream(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use crate::util::ParseError;
+
 #[derive(Debug)]
 struct File {
     size: u32,
@@ -42,7 +44,19 @@ impl Directory {
     }
 }
 
-pub fn parse_terminal_output(output: &str) -> HashMap<String, Directory> {
+fn parse_file(entry: &str) -> Result<File, ParseError> {
+    let (size, name) = entry
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new("a file size and name", entry))?;
+
+    let size = size
+        .parse()
+        .map_err(|_| ParseError::in_line(entry, size, "a file size"))?;
+
+    Ok(File::new(name, size))
+}
+
+pub fn parse_terminal_output(output: &str) -> Result<HashMap<String, Directory>, ParseError> {
     let base = Directory {
         name: "/".to_string(),
         files: vec![],
@@ -59,6 +73,7 @@ pub fn parse_terminal_output(output: &str) -> HashMap<String, Directory> {
 
     for i in 0..lines.len() {
         let line = *lines.get(i).unwrap();
+        let error_at = |error: ParseError| error.offset_lines(i);
 
         match line {
             "$ cd /" => {
@@ -66,12 +81,24 @@ pub fn parse_terminal_output(output: &str) -> HashMap<String, Directory> {
             }
             "$ cd .." => {
                 let directory = directories.get(&current_directory).unwrap();
-                current_directory = directory.parent_name.as_ref().unwrap().to_string();
+                current_directory = directory
+                    .parent_name
+                    .clone()
+                    .ok_or_else(|| error_at(ParseError::new("a directory below /", line)))?;
             }
             n if n.starts_with("$ cd ") => {
                 let name: String = n.chars().skip(5).collect();
 
                 let name: String = format!("{}/{}", current_directory, name);
+                if !directories.contains_key(&name) {
+                    let token = &n[5..];
+                    return Err(error_at(ParseError::in_line(
+                        n,
+                        token,
+                        "a listed directory",
+                    )));
+                }
+
                 current_directory = name;
             }
             "$ ls" => {
@@ -84,7 +111,7 @@ pub fn parse_terminal_output(output: &str) -> HashMap<String, Directory> {
                 let mut files = vec![];
                 let mut children = vec![];
 
-                for entry in contents {
+                for (offset, entry) in contents.into_iter().enumerate() {
                     if entry.starts_with("dir") {
                         let name: String = entry.chars().skip(4).collect();
                         let name: String = format!("{}/{}", current_directory, name);
@@ -99,29 +126,30 @@ pub fn parse_terminal_output(output: &str) -> HashMap<String, Directory> {
                         children.push(name.clone());
                         directories.insert(name, directory);
                     } else {
-                        let mut components = entry.split(" ");
-                        let size = components.next().unwrap().parse().unwrap();
-                        let name = components.next().unwrap();
+                        let file = parse_file(entry)
+                            .map_err(|error| error.offset_lines(i + 1 + offset))?;
 
-                        files.push(File::new(name, size));
+                        files.push(file);
                     }
                 }
 
                 directories.get_mut(&current_directory).unwrap().files = files;
                 directories.get_mut(&current_directory).unwrap().children = children;
             }
-            n if n.starts_with('$') => {}
+            n if n.starts_with('$') => {
+                return Err(error_at(ParseError::new("a cd or ls command", n)));
+            }
             _ => {}
         }
     }
 
-    directories
+    Ok(directories)
 }
 
 pub fn list_directories_by_size(directories: &HashMap<String, Directory>) -> HashMap<String, u32> {
     let mut result = HashMap::new();
 
-    directories.values().into_iter().for_each(|directory| {
+    directories.values().for_each(|directory| {
         let size = directory.total_size(directories);
 
         result.insert(directory.name.clone(), size);
@@ -145,17 +173,13 @@ pub fn sum_directories_with_max_size(
 }
 
 pub fn smallest_deletable_directory_size(
-    input: &str,
+    directories: &HashMap<String, Directory>,
     required_space: u32,
     total_space: u32,
 ) -> u32 {
-    let directories = parse_terminal_output(input);
-
-    let by_size = list_directories_by_size(&directories);
-    let unused_space = total_space - by_size.get("/").unwrap();
-    let missing = required_space - unused_space;
-
-    println!("unused:{}, missing {}", unused_space, missing);
+    let by_size = list_directories_by_size(directories);
+    let unused_space = total_space.saturating_sub(*by_size.get("/").unwrap());
+    let missing = required_space.saturating_sub(unused_space);
 
     by_size.iter().fold(total_space, |current, directory| {
         let (_, size) = directory;
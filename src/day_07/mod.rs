@@ -1,31 +1,79 @@
 mod directory;
 
-use directory::{
-    parse_terminal_output, smallest_deletable_directory_size, sum_directories_with_max_size,
+use std::collections::HashMap;
+
+use crate::{
+    solver::{Answer, Solver},
+    util::{ParseError, Rng},
+};
+pub use directory::{
+    list_directories_by_size, parse_terminal_output, smallest_deletable_directory_size,
+    sum_directories_with_max_size, Directory,
 };
 
-use crate::util::read_input;
+fn list_directory(
+    rng: &mut Rng,
+    children: &[Vec<usize>],
+    directory: usize,
+    max_file_size: i64,
+    lines: &mut Vec<String>,
+) {
+    let mut entries: Vec<String> = children[directory]
+        .iter()
+        .map(|child| format!("dir d{child}"))
+        .collect();
+
+    for file in 0..rng.below(4) {
+        entries.push(format!("{} f{file}.txt", rng.range(1..=max_file_size)));
+    }
 
-fn find_directories_with_max_size_by_output(output: &str, max_size: u32) -> u32 {
-    let directories = parse_terminal_output(output);
+    rng.shuffle(&mut entries);
+    lines.push("$ ls".to_string());
+    lines.extend(entries);
 
-    sum_directories_with_max_size(&directories, max_size)
+    for child in &children[directory] {
+        lines.push(format!("$ cd d{child}"));
+        list_directory(rng, children, *child, max_file_size, lines);
+        lines.push("$ cd ..".to_string());
+    }
 }
 
-pub fn solve_part_1() -> u32 {
-    let input = read_input("src/day_07/input.txt");
+fn generate_terminal_output(rng: &mut Rng, size: usize) -> String {
+    let max_file_size = (60_000_000 / (3 * size as i64 + 3)).max(1);
+    let mut children = vec![vec![]; size + 1];
+    let mut lines = vec!["$ cd /".to_string()];
 
-    find_directories_with_max_size_by_output(&input, 100000)
-}
+    for directory in 1..=size {
+        children[rng.below(directory)].push(directory);
+    }
+
+    list_directory(rng, &children, 0, max_file_size, &mut lines);
 
-fn find_smallest_deletable_directory_by_output(output: &str) -> u32 {
-    smallest_deletable_directory_size(output, 30000000, 70000000)
+    lines.join("\n")
 }
 
-pub fn solve_part_2() -> u32 {
-    let input = read_input("src/day_07/input.txt");
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = HashMap<String, Directory>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_terminal_output(input)
+    }
 
-    find_smallest_deletable_directory_by_output(&input)
+    fn solve_part_1(directories: &Self::Input) -> Answer {
+        sum_directories_with_max_size(directories, 100000).into()
+    }
+
+    fn solve_part_2(directories: &Self::Input) -> Answer {
+        smallest_deletable_directory_size(directories, 30000000, 70000000).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_terminal_output(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -34,17 +82,34 @@ mod tests {
 
     #[test]
     fn finds_directories_with_max_size_by_output() {
-        let output = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k";
-        let result = find_directories_with_max_size_by_output(output, 100000);
+        let output = include_str!("examples/example.txt");
+        let directories = parse_terminal_output(output).unwrap();
+        let result = sum_directories_with_max_size(&directories, 100000);
 
         assert_eq!(result, 95437);
     }
 
     #[test]
     fn finds_smallest_deletable_directory() {
-        let output = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k";
-        let result = find_smallest_deletable_directory_by_output(output);
+        let output = include_str!("examples/example.txt");
+        let directories = parse_terminal_output(output).unwrap();
+        let result = smallest_deletable_directory_size(&directories, 30000000, 70000000);
 
         assert_eq!(result, 24933642);
     }
+
+    #[test]
+    fn rejects_invalid_terminal_output() {
+        let error = parse_terminal_output("$ cd /\n$ ls\ndir a\n12x b.txt").unwrap_err();
+        assert_eq!((error.line, error.token.as_str()), (4, "12x"));
+
+        let error = parse_terminal_output("$ cd /\n$ cd ..").unwrap_err();
+        assert_eq!(error.line, 2);
+
+        let error = parse_terminal_output("$ cd /\n$ cd b").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+
+        let error = parse_terminal_output("$ cd /\n$ rm -rf").unwrap_err();
+        assert_eq!(error.token, "$ rm -rf");
+    }
 }
@@ -1,57 +1,48 @@
-use crate::model::Position2d;
-use std::{collections::HashMap, ops::Range};
+use crate::{
+    model::{Grid, Position2d},
+    util::ParseError,
+};
+use std::str::FromStr;
 
 type Position = Position2d<usize>;
 
 pub struct Map2d {
-    tiles: HashMap<Position, usize>,
-    size_x: usize,
-    size_y: usize,
+    heights: Grid<usize>,
 }
 
-impl Map2d {
-    pub fn from_string(input: &str) -> Self {
-        let mut tiles = HashMap::new();
-        let mut size_x = 0;
-        let mut size_y = 0;
-
-        input.lines().enumerate().for_each(|(y, line)| {
-            size_y = y;
-
-            line.chars().enumerate().for_each(|(x, height)| {
-                let height = height.to_string().parse().unwrap();
-
-                tiles.insert((x, y), height);
-                size_x = x;
-            });
-        });
-
-        Self {
-            tiles,
-            size_x,
-            size_y,
-        }
+impl FromStr for Map2d {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let heights = Grid::parse_with(input, "a tree height", |height| {
+            height.to_digit(10).map(|height| height as usize)
+        })?;
+
+        Ok(Self { heights })
     }
 }
 
 fn to_x_positions(range: impl Iterator<Item = usize>, y: usize) -> Vec<Position> {
-    range.map(|x| (x, y)).collect()
+    range.map(|x| Position::new(x, y)).collect()
 }
 
 fn to_y_positions(range: impl Iterator<Item = usize>, x: usize) -> Vec<Position> {
-    range.map(|y| (x, y)).collect()
+    range.map(|y| Position::new(x, y)).collect()
 }
 
 impl Map2d {
-    fn find_visible_trees(&self) -> HashMap<Position, bool> {
-        let mut visibility = HashMap::new();
+    fn find_visible_trees(&self) -> Grid<bool> {
+        let mut visibility = self.heights.map(|_| false);
+        let (width, height) = (self.heights.width(), self.heights.height());
 
-        for y in 0..=self.size_y {
-            self.traverse_column(y, 0..(self.size_x + 1), &mut visibility);
+        for y in 0..height {
+            self.traverse(to_x_positions(0..width, y), &mut visibility);
+            self.traverse(to_x_positions((0..width).rev(), y), &mut visibility);
         }
 
-        for x in 0..=self.size_x {
-            self.traverse_row(x, 0..(self.size_y + 1), &mut visibility);
+        for x in 0..width {
+            self.traverse(to_y_positions(0..height, x), &mut visibility);
+            self.traverse(to_y_positions((0..height).rev(), x), &mut visibility);
         }
 
         visibility
@@ -59,110 +50,35 @@ impl Map2d {
 
     pub fn count_visible_trees(&self) -> usize {
         self.find_visible_trees()
-            .values()
-            .fold(0, |total, is_visible| match is_visible {
+            .iter()
+            .fold(0, |total, (_, is_visible)| match is_visible {
                 true => total + 1,
                 false => total,
             })
     }
 
-    fn traverse_column(
-        &self,
-        y: usize,
-        rows: Range<usize>,
-        visibility: &mut HashMap<Position, bool>,
-    ) {
-        let mut max_height = 0;
-
-        for x in rows.clone() {
-            max_height = self.determine_if_visible(x, y, max_height, visibility);
-        }
-
-        max_height = 0;
-
-        for x in rows.rev() {
-            max_height = self.determine_if_visible(x, y, max_height, visibility);
-        }
-    }
+    fn traverse(&self, line: Vec<Position>, visibility: &mut Grid<bool>) {
+        let mut max_height = None;
 
-    fn traverse_row(
-        &self,
-        x: usize,
-        columns: Range<usize>,
-        visibility: &mut HashMap<Position, bool>,
-    ) {
-        let mut max_height = 0;
+        for position in line {
+            let height = self.heights[position];
 
-        for y in columns.clone() {
-            max_height = self.determine_if_visible(x, y, max_height, visibility);
-        }
-
-        max_height = 0;
-
-        for y in columns.rev() {
-            max_height = self.determine_if_visible(x, y, max_height, visibility);
-        }
-    }
-
-    fn determine_if_visible(
-        &self,
-        x: usize,
-        y: usize,
-        max_height: usize,
-        visibility: &mut HashMap<Position, bool>,
-    ) -> usize {
-        let position = (x, y);
-
-        let visible = visibility.get(&position);
-
-        let height = self.tiles.get(&position).unwrap();
-        let is_edge = y == 0 || x == 0 || y == self.size_y || x == self.size_x;
-        let new_max_height = match height > &max_height {
-            true => *height,
-            false => max_height,
-        };
-
-        match visible {
-            Some(visible) if *visible => {
-                return new_max_height;
+            if max_height.is_none_or(|max_height| height > max_height) {
+                visibility[position] = true;
+                max_height = Some(height);
             }
-            _ => {}
         }
-
-        let visible = match is_edge {
-            true => true,
-            false => height > &max_height,
-        };
-
-        visibility.insert(position, visible);
-
-        new_max_height
-    }
-
-    fn find_scenic_scores(&self) -> HashMap<Position, usize> {
-        let mut scores = HashMap::new();
-
-        for x in 0..=self.size_x {
-            for y in 0..=self.size_y {
-                let score = self.scenic_score_at(x, y);
-
-                scores.insert((x, y), score);
-            }
-        }
-
-        scores
     }
 
     fn scenic_score_at(&self, x: usize, y: usize) -> usize {
-        let position = (x, y);
-
-        let vantage = self.tiles.get(&position).unwrap();
+        let vantage = self.heights[Position::new(x, y)];
+        let (width, height) = (self.heights.width(), self.heights.height());
 
         let ranges = vec![
-            to_x_positions((0..x).into_iter().rev(), y),
-            to_x_positions((x + 1)..(self.size_x + 1), y),
-            to_y_positions((0..y).into_iter().rev(), x),
-            to_y_positions((y + 1)..(self.size_y + 1), x),
+            to_x_positions((0..x).rev(), y),
+            to_x_positions((x + 1)..width, y),
+            to_y_positions((0..y).rev(), x),
+            to_y_positions((y + 1)..height, x),
         ];
 
         ranges
@@ -171,22 +87,24 @@ impl Map2d {
                 let mut range_score = 0;
 
                 for position in range {
-                    let height = self.tiles.get(&position).unwrap();
-
                     range_score += 1;
 
-                    if height >= vantage {
+                    if self.heights[position] >= vantage {
                         break;
                     }
                 }
 
                 range_score
             })
-            .fold(1, |result, direction_score| result * direction_score)
+            .product()
     }
 
     pub fn best_scenic_score(&self) -> usize {
-        *self.find_scenic_scores().values().max().unwrap_or(&0)
+        self.heights
+            .positions()
+            .map(|position| self.scenic_score_at(position.x, position.y))
+            .max()
+            .unwrap_or(0)
     }
 }
 
@@ -197,21 +115,29 @@ mod tests {
     #[test]
     fn counts_visible_trees() {
         let input = "30373\n25512\n65332\n33549\n35390";
-        let map = Map2d::from_string(input);
+        let map: Map2d = input.parse().unwrap();
 
         let result = map.count_visible_trees();
         assert_eq!(result, 21)
     }
+    #[test]
+    fn handles_single_tree() {
+        let map: Map2d = "7".parse().unwrap();
+
+        assert_eq!(map.count_visible_trees(), 1);
+        assert_eq!(map.best_scenic_score(), 0);
+    }
+
     #[test]
     fn counts_visible_trees_small_example() {
         let input = "1111\n1201\n1021\n1111";
-        let map = Map2d::from_string(input);
+        let map: Map2d = input.parse().unwrap();
 
         let visibility = map.find_visible_trees();
-        assert_eq!(visibility.get(&(1, 1)), Some(&true));
-        assert_eq!(visibility.get(&(2, 1)), Some(&false));
-        assert_eq!(visibility.get(&(1, 2)), Some(&false));
-        assert_eq!(visibility.get(&(2, 2)), Some(&true));
+        assert_eq!(visibility.get(1, 1), Some(&true));
+        assert_eq!(visibility.get(2, 1), Some(&false));
+        assert_eq!(visibility.get(1, 2), Some(&false));
+        assert_eq!(visibility.get(2, 2), Some(&true));
 
         let result = map.count_visible_trees();
         assert_eq!(result, 14);
@@ -220,19 +146,19 @@ mod tests {
     #[test]
     fn determines_visible_trees() {
         let input = "30373\n25512\n65332\n33549\n35390";
-        let map = Map2d::from_string(input);
+        let map: Map2d = input.parse().unwrap();
         let visibility = map.find_visible_trees();
 
-        assert_eq!(visibility.get(&(1, 1)), Some(&true));
-        assert_eq!(visibility.get(&(2, 1)), Some(&true));
-        assert_eq!(visibility.get(&(0, 2)), Some(&true));
-        assert_eq!(visibility.get(&(3, 1)), Some(&false));
+        assert_eq!(visibility.get(1, 1), Some(&true));
+        assert_eq!(visibility.get(2, 1), Some(&true));
+        assert_eq!(visibility.get(0, 2), Some(&true));
+        assert_eq!(visibility.get(3, 1), Some(&false));
     }
 
     #[test]
     fn determines_scenic_score_at_position() {
         let input = "30373\n25512\n65332\n33549\n35390";
-        let map = Map2d::from_string(input);
+        let map: Map2d = input.parse().unwrap();
 
         assert_eq!(map.scenic_score_at(2, 1), 4);
         assert_eq!(map.scenic_score_at(2, 3), 8);
@@ -241,9 +167,20 @@ mod tests {
     #[test]
     fn determines_best_scenic_score() {
         let input = "30373\n25512\n65332\n33549\n35390";
-        let map = Map2d::from_string(input);
+        let map: Map2d = input.parse().unwrap();
         let best = map.best_scenic_score();
 
         assert_eq!(best, 8);
     }
+
+    #[test]
+    fn rejects_invalid_maps() {
+        let error = "303\n2x5".parse::<Map2d>().err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = "303\n25".parse::<Map2d>().err().unwrap();
+        assert_eq!(error.line, 2);
+
+        assert!("".parse::<Map2d>().is_err());
+    }
 }
@@ -1,21 +1,41 @@
 mod map_2d;
 
-use map_2d::Map2d;
+use crate::{
+    solver::{Answer, Solver},
+    util::{ParseError, Rng},
+};
+pub use map_2d::Map2d;
+
+fn generate_forest(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(1);
+    let height = 1 + rng.below(width);
+
+    (0..height)
+        .map(|_| (0..width).map(|_| rng.below(10).to_string()).collect())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
 
-use crate::util::read_input;
+pub struct Day08;
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_08/input.txt");
+impl Solver for Day08 {
+    const DAY: u8 = 8;
 
-    let map = Map2d::from_string(&input);
+    type Input = Map2d;
 
-    map.count_visible_trees()
-}
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_08/input.txt");
+    fn solve_part_1(map: &Self::Input) -> Answer {
+        map.count_visible_trees().into()
+    }
 
-    let map = Map2d::from_string(&input);
+    fn solve_part_2(map: &Self::Input) -> Answer {
+        map.best_scenic_score().into()
+    }
 
-    map.best_scenic_score()
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_forest(rng, size))
+    }
 }
@@ -1,16 +1,39 @@
 mod rope;
 
-use crate::util::read_input;
-use rope::determine_rope_tail_steps;
+use crate::{
+    model::Direction,
+    solver::{Answer, Solver},
+    util::{ParseError, Rng},
+};
+pub use rope::{determine_rope_tail_steps, parse_steps, Rope};
 
-pub fn solve_part_1() -> usize {
-    let input = read_input("src/day_09/input.txt");
-
-    determine_rope_tail_steps(&input, 2).len()
+fn generate_steps(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{} {}", rng.pick(&["U", "D", "L", "R"]), rng.below(11)))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-pub fn solve_part_2() -> usize {
-    let input = read_input("src/day_09/input.txt");
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<(Direction, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_steps(input)
+    }
+
+    fn solve_part_1(steps: &Self::Input) -> Answer {
+        determine_rope_tail_steps(steps, 2).len().into()
+    }
+
+    fn solve_part_2(steps: &Self::Input) -> Answer {
+        determine_rope_tail_steps(steps, 10).len().into()
+    }
 
-    determine_rope_tail_steps(&input, 10).len()
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_steps(rng, size))
+    }
 }
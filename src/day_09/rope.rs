@@ -1,9 +1,12 @@
-use crate::model::{Direction, Position2d};
+use crate::{
+    model::{Direction, Position2d},
+    util::ParseError,
+};
 use std::collections::HashSet;
 
 type Position = Position2d<isize>;
 
-struct Rope {
+pub struct Rope {
     head_position: Position,
     tail_positions: Vec<Position>,
 
@@ -12,76 +15,36 @@ struct Rope {
 
 impl Rope {
     pub fn new(knots: usize) -> Self {
-        let mut tail_positions = vec![];
-
-        for _ in 0..(knots - 1) {
-            tail_positions.push((0, 0));
-        }
-
         Self {
-            head_position: (0, 0),
-            tail_positions,
-            tail_visits: HashSet::new(),
+            head_position: Position::origin(),
+            tail_positions: vec![Position::origin(); knots - 1],
+            tail_visits: HashSet::from([Position::origin()]),
         }
     }
 }
 
-fn calculate_position_difference(x_head: &isize, x_tail: &isize) -> (isize, isize) {
-    let difference_x = x_head - x_tail;
-    let difference_x_abs = difference_x.abs();
-    let direction_x = match difference_x_abs {
-        0 => 0,
-        _ => difference_x / difference_x_abs,
-    };
-
-    (difference_x_abs, direction_x)
-}
-
-fn minimise_distance_step(difference_abs: isize, direction: isize, is_diagonal: bool) -> isize {
-    match difference_abs {
-        2 => 1 * direction,
-        1 if is_diagonal => 1 * direction,
-        _ => 0,
+fn follow(head: &Position, tail: &Position) -> Position {
+    match head.chebyshev(tail) > 1 {
+        true => *tail + (*head - *tail).signum(),
+        false => *tail,
     }
 }
 
-fn minimise_distance_between(head: &Position, tail: &Position) -> Position {
-    let (x_head, y_head) = head;
-    let (x_tail, y_tail) = tail;
-
-    let (diff_x_abs, direction_x) = calculate_position_difference(x_head, x_tail);
-    let (diff_y_abs, direction_y) = calculate_position_difference(y_head, y_tail);
-
-    let is_diagonal = diff_x_abs + diff_y_abs > 2;
-
-    let new_x_tail = x_tail + minimise_distance_step(diff_x_abs, direction_x, is_diagonal);
-    let new_y_tail = y_tail + minimise_distance_step(diff_y_abs, direction_y, is_diagonal);
-
-    (new_x_tail, new_y_tail)
-}
-
 impl Rope {
-    pub fn move_steps(&mut self, steps: &Vec<(Direction, isize)>) {
+    pub fn move_steps(&mut self, steps: &[(Direction, usize)]) {
         for (direction, count) in steps {
             self.move_head(direction, *count);
         }
     }
 
-    fn move_head(&mut self, direction: &Direction, steps: isize) {
-        for _ in 0..steps {
-            self.move_head_once(&direction);
-            self.move_tail_once();
-        }
+    pub fn tail_visits(&self) -> &HashSet<Position> {
+        &self.tail_visits
     }
 
-    fn move_head_once(&mut self, direction: &Direction) {
-        let (x, y) = self.head_position;
-
-        match direction {
-            Direction::Up => self.head_position = (x, y + 1),
-            Direction::Down => self.head_position = (x, y - 1),
-            Direction::Left => self.head_position = (x - 1, y),
-            Direction::Right => self.head_position = (x + 1, y),
+    fn move_head(&mut self, direction: &Direction, steps: usize) {
+        for _ in 0..steps {
+            self.head_position += direction.delta();
+            self.move_tail_once();
         }
     }
 
@@ -91,7 +54,7 @@ impl Rope {
         for tail_position in &self.tail_positions {
             let next = new_tail.last().unwrap_or(&self.head_position);
 
-            let new_tail_position = minimise_distance_between(next, &tail_position);
+            let new_tail_position = follow(next, tail_position);
             new_tail.push(new_tail_position);
         }
 
@@ -102,31 +65,36 @@ impl Rope {
     }
 }
 
-pub fn parse_steps(input: &str) -> Vec<(Direction, isize)> {
-    input
-        .lines()
-        .map(|line| {
-            let mut components = line.split(" ");
+fn parse_step(line: &str) -> Result<(Direction, usize), ParseError> {
+    let (direction, steps) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new("a direction and a step count", line))?;
 
-            let direction = match components.nth(0).unwrap() {
-                "L" => Direction::Left,
-                "R" => Direction::Right,
-                "U" => Direction::Up,
-                _ => Direction::Down,
-            };
+    let direction = direction
+        .parse()
+        .map_err(|error: ParseError| ParseError::in_line(line, direction, &error.expected))?;
 
-            let steps = components.nth(0).unwrap().parse().unwrap();
+    let steps = match steps.starts_with(['-', '+']) {
+        true => None,
+        false => steps.parse().ok(),
+    }
+    .ok_or_else(|| ParseError::in_line(line, steps, "an unsigned step count"))?;
 
-            (direction, steps)
-        })
+    Ok((direction, steps))
+}
+
+pub fn parse_steps(input: &str) -> Result<Vec<(Direction, usize)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_step(line).map_err(|error| error.offset_lines(index)))
         .collect()
 }
 
-pub fn determine_rope_tail_steps(input: &str, knots: usize) -> HashSet<Position> {
+pub fn determine_rope_tail_steps(steps: &[(Direction, usize)], knots: usize) -> HashSet<Position> {
     let mut rope = Rope::new(knots);
-    let steps = parse_steps(input);
 
-    rope.move_steps(&steps);
+    rope.move_steps(steps);
 
     rope.tail_visits
 }
@@ -138,16 +106,49 @@ mod tests {
     #[test]
     fn moves_rope_1_knot() {
         let input = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2";
-        let steps = determine_rope_tail_steps(input, 2);
+        let steps = determine_rope_tail_steps(&parse_steps(input).unwrap(), 2);
 
         assert_eq!(steps.len(), 13);
     }
 
+    #[test]
+    fn counts_start_for_zero_length_moves() {
+        let steps = determine_rope_tail_steps(&parse_steps("R 0\nU 0").unwrap(), 2);
+
+        assert_eq!(steps.len(), 1);
+    }
+
     #[test]
     fn moves_rope_10_knots() {
         let input = "R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20";
-        let steps = determine_rope_tail_steps(input, 10);
+        let steps = determine_rope_tail_steps(&parse_steps(input).unwrap(), 10);
 
         assert_eq!(steps.len(), 36);
     }
+
+    #[test]
+    fn follows_head_diagonally() {
+        let tail = Position::origin();
+
+        assert_eq!(follow(&Position::new(1, 1), &tail), tail);
+        assert_eq!(follow(&Position::new(2, 0), &tail), Position::new(1, 0));
+        assert_eq!(follow(&Position::new(2, -1), &tail), Position::new(1, -1));
+    }
+
+    #[test]
+    fn rejects_invalid_steps() {
+        let error = parse_steps("R 4\nX 4").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = parse_steps("R 4\nU four").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        for input in ["L -3", "L +3"] {
+            let error = parse_steps(input).unwrap_err();
+            assert_eq!(
+                (error.column, error.expected.as_str()),
+                (3, "an unsigned step count")
+            );
+        }
+    }
 }
@@ -1,14 +1,16 @@
 mod program;
 
-use crate::util::read_input;
-use program::{Instruction, Program};
+use crate::{
+    solver::{Answer, Solver},
+    util::{parse_lines, ParseError, Rng},
+};
+pub use program::{Instruction, Program};
 
-fn record_program_execution_from_input(input: &str) -> i32 {
+fn record_program_execution(instructions: &[Instruction]) -> i32 {
     let mut program = Program::new();
-    let instructions = Instruction::multiple_from_string(&input);
     let record_times = [20, 60, 100, 140, 180, 220];
 
-    let recordings = program.record_execution(&instructions, &record_times);
+    let recordings = program.record_execution(instructions, &record_times);
 
     recordings
         .into_iter()
@@ -17,23 +19,44 @@ fn record_program_execution_from_input(input: &str) -> i32 {
         .sum()
 }
 
-pub fn solve_part_1() -> i32 {
-    let input = read_input("src/day_10/input.txt");
+fn draw_crt(instructions: &[Instruction]) -> String {
+    let mut program = Program::new();
 
-    record_program_execution_from_input(&input)
+    program.draw_crt(instructions)
 }
 
-fn draw_crt_from_input(input: &str) -> String {
-    let mut program = Program::new();
-    let instructions = Instruction::multiple_from_string(&input);
-
-    program.draw_crt(&instructions)
+fn generate_instructions(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| match rng.chance(1, 3) {
+            true => "noop".to_string(),
+            false => format!("addx {}", rng.range(-20..=20)),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-pub fn solve_part_2() -> String {
-    let input = read_input("src/day_10/input.txt");
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
 
-    draw_crt_from_input(&input)
+    fn solve_part_1(instructions: &Self::Input) -> Answer {
+        record_program_execution(instructions).into()
+    }
+
+    fn solve_part_2(instructions: &Self::Input) -> Answer {
+        draw_crt(instructions).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate_instructions(rng, size))
+    }
 }
 
 #[cfg(test)]
@@ -42,17 +65,26 @@ mod tests {
 
     #[test]
     fn sums_recorded_register_values() {
-        let input = "addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop";
-        let result = record_program_execution_from_input(input);
+        let input = include_str!("examples/example.txt");
+        let result = record_program_execution(&parse_lines(input).unwrap());
 
         assert_eq!(result, 13140);
     }
 
     #[test]
     fn draws_on_crt() {
-        let input = "addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop";
-        let result = draw_crt_from_input(input);
+        let input = include_str!("examples/example.txt");
+        let result = draw_crt(&parse_lines(input).unwrap());
+
+        let expected = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ];
 
-        println!("{}", result);
+        assert_eq!(result, format!("\n{}", expected.join("\n")));
     }
 }
@@ -1,25 +1,26 @@
+use std::str::FromStr;
+
+use crate::util::ParseError;
+
+#[derive(Debug, PartialEq)]
 pub enum Instruction {
     Noop,
     Addx(i32),
 }
 
-impl Instruction {
-    pub fn from_string(input: &str) -> Self {
-        match input {
-            "noop" => Self::Noop,
-            s => {
-                let number: i32 = s.split(" ").nth(1).unwrap().parse().unwrap();
-                Self::Addx(number)
-            }
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.split_once(' ') {
+            None if input == "noop" => Ok(Self::Noop),
+            Some(("addx", value)) => value
+                .parse()
+                .map(Self::Addx)
+                .map_err(|_| ParseError::in_line(input, value, "a register value")),
+            _ => Err(ParseError::new("'noop' or 'addx <value>'", input)),
         }
     }
-
-    pub fn multiple_from_string(input: &str) -> Vec<Self> {
-        input
-            .lines()
-            .map(|line| Instruction::from_string(line))
-            .collect()
-    }
 }
 
 pub struct Program {
@@ -36,6 +37,12 @@ impl Program {
     }
 }
 
+impl Default for Program {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Program {
     fn capture_recording(&self, record_at: &u32, current: Option<i32>) -> Option<i32> {
         match current {
@@ -66,7 +73,7 @@ impl Program {
 
     pub fn record_execution(
         &mut self,
-        instructions: &Vec<Instruction>,
+        instructions: &[Instruction],
         record_times: &[u32],
     ) -> Vec<i32> {
         let mut recordings: Vec<i32> = vec![];
@@ -74,9 +81,8 @@ impl Program {
         let mut record_at = record_times.next();
 
         for instruction in instructions {
-            match record_at {
-                None => break,
-                _ => {}
+            if record_at.is_none() {
+                break;
             }
 
             let recording = self.execute_instruction(instruction, record_at.unwrap());
@@ -113,7 +119,7 @@ impl Program {
         result
     }
 
-    pub fn draw_crt(&mut self, instructions: &Vec<Instruction>) -> String {
+    pub fn draw_crt(&mut self, instructions: &[Instruction]) -> String {
         let mut result = String::new();
 
         let mut cycle = 0;
@@ -127,22 +133,6 @@ impl Program {
                     result.push('\n');
                 }
 
-                println!(
-                    "pixel position: {}, sprites: {},{},{}",
-                    pointer,
-                    value - 1,
-                    value,
-                    value + 1,
-                );
-
-                println!(
-                    "test: {} <= {} <= {} ({})",
-                    value - 1,
-                    pointer,
-                    value + 1,
-                    value - 1 <= pointer && value + 1 >= pointer
-                );
-
                 match value - 1 <= pointer && value + 1 >= pointer {
                     true => result.push('#'),
                     false => result.push('.'),
@@ -155,3 +145,23 @@ impl Program {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_instructions() {
+        assert_eq!("noop".parse(), Ok(Instruction::Noop));
+        assert_eq!("addx -11".parse(), Ok(Instruction::Addx(-11)));
+    }
+
+    #[test]
+    fn rejects_invalid_instructions() {
+        let error = "addx 1x".parse::<Instruction>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (6, "1x"));
+
+        assert!("jmp 4".parse::<Instruction>().is_err());
+        assert!("noop 1".parse::<Instruction>().is_err());
+    }
+}
@@ -0,0 +1,16 @@
+pub mod cli;
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod model;
+pub mod solver;
+pub mod util;
+
+pub use solver::{find_puzzle, registry, Answer, AnswerSheet, Part, Puzzle, Run, Solution, Solver};
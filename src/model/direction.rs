@@ -1,7 +1,258 @@
-#[derive(Debug, PartialEq)]
+use std::{ops::Neg, str::FromStr};
+
+use super::{Coordinate, Position2d};
+use crate::util::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction {
     Up,
     Down,
     Left,
     Right,
 }
+
+impl Direction {
+    pub fn all() -> [Direction; 4] {
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    pub fn delta<T: Coordinate + Neg<Output = T>>(&self) -> Position2d<T> {
+        Position2d::from(*self)
+    }
+
+    pub fn offset(&self) -> (i128, i128) {
+        Direction8::from(*self).offset()
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        match token {
+            "U" | "N" | "^" | "↑" => Ok(Direction::Up),
+            "D" | "S" | "v" | "↓" => Ok(Direction::Down),
+            "L" | "W" | "<" | "←" => Ok(Direction::Left),
+            "R" | "E" | ">" | "→" => Ok(Direction::Right),
+            _ => Err(ParseError::new(
+                "one of U, D, L, R, N, S, W, E or an arrow",
+                token,
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub fn all() -> [Direction8; 8] {
+        [
+            Direction8::Up,
+            Direction8::UpRight,
+            Direction8::Right,
+            Direction8::DownRight,
+            Direction8::Down,
+            Direction8::DownLeft,
+            Direction8::Left,
+            Direction8::UpLeft,
+        ]
+    }
+
+    fn index(&self) -> usize {
+        Self::all()
+            .iter()
+            .position(|direction| direction == self)
+            .unwrap()
+    }
+
+    fn rotated(&self, eighths: usize) -> Self {
+        Self::all()[(self.index() + eighths) % 8]
+    }
+
+    pub fn turn_right(&self) -> Self {
+        self.rotated(1)
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.rotated(7)
+    }
+
+    pub fn reverse(&self) -> Self {
+        self.rotated(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    pub fn offset(&self) -> (i128, i128) {
+        let delta: Position2d<i128> = self.delta();
+
+        (delta.x, delta.y)
+    }
+
+    pub fn delta<T: Coordinate + Neg<Output = T>>(&self) -> Position2d<T> {
+        let (up, right, down, left) = (
+            Direction::Up.delta(),
+            Direction::Right.delta(),
+            Direction::Down.delta(),
+            Direction::Left.delta(),
+        );
+
+        match self {
+            Direction8::Up => up,
+            Direction8::UpRight => up + right,
+            Direction8::Right => right,
+            Direction8::DownRight => down + right,
+            Direction8::Down => down,
+            Direction8::DownLeft => down + left,
+            Direction8::Left => left,
+            Direction8::UpLeft => up + left,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+impl FromStr for Direction8 {
+    type Err = ParseError;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        match token {
+            "NE" | "UR" | "↗" => Ok(Direction8::UpRight),
+            "SE" | "DR" | "↘" => Ok(Direction8::DownRight),
+            "SW" | "DL" | "↙" => Ok(Direction8::DownLeft),
+            "NW" | "UL" | "↖" => Ok(Direction8::UpLeft),
+            _ => token
+                .parse::<Direction>()
+                .map(Direction8::from)
+                .map_err(|_| {
+                    ParseError::new(
+                        "a direction or one of NE, SE, SW, NW or a diagonal arrow",
+                        token,
+                    )
+                }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_directions() {
+        for token in ["U", "N", "^", "↑"] {
+            assert_eq!(token.parse(), Ok(Direction::Up));
+        }
+        assert_eq!("W".parse(), Ok(Direction::Left));
+        assert_eq!(">".parse(), Ok(Direction::Right));
+        assert_eq!("↓".parse(), Ok(Direction::Down));
+        assert_eq!("↙".parse(), Ok(Direction8::DownLeft));
+        assert_eq!("E".parse(), Ok(Direction8::Right));
+
+        assert!("X".parse::<Direction>().is_err());
+        assert!("NE".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn steps_on_unsigned_positions() {
+        let corner = Position2d::new(0_usize, 0);
+
+        assert_eq!(corner.step(Direction::Up), None);
+        assert_eq!(corner.step(Direction::Left), None);
+        assert_eq!(corner.step(Direction::Down), Some(Position2d::new(0, 1)));
+        assert_eq!(
+            corner.step("v".parse::<Direction>().unwrap()),
+            Some(Position2d::new(0, 1))
+        );
+        assert_eq!(
+            Position2d::new(2_usize, 2).step(Direction8::UpRight),
+            Some(Position2d::new(3, 1))
+        );
+    }
+
+    #[test]
+    fn turns_and_reverses() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert!(Direction::all()
+            .iter()
+            .all(|direction| direction.turn_left().turn_right() == *direction));
+
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.reverse(), Direction8::UpLeft);
+    }
+
+    #[test]
+    fn has_unit_deltas() {
+        assert_eq!(Direction::Up.delta(), Position2d::new(0, -1));
+        assert_eq!(Direction8::DownLeft.delta(), Position2d::new(-1, 1));
+
+        let total = Direction8::all()
+            .iter()
+            .fold(Position2d::origin(), |total, direction| {
+                total + direction.delta::<i32>()
+            });
+        assert_eq!(total, Position2d::origin());
+        assert!(Direction8::all().iter().all(|direction| {
+            let delta = direction.delta::<i128>();
+            direction.offset() == (delta.x, delta.y)
+        }));
+        assert_eq!(
+            Direction8::all()
+                .iter()
+                .filter(|direction| direction.is_diagonal())
+                .count(),
+            4
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use super::Position2d;
+use crate::util::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid cells must fill the grid");
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn parse_with<F>(input: &str, expected: &str, mapper: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let row_width = line.chars().count();
+
+            if row_width == 0 || (y > 0 && row_width != width) {
+                let expected = format!("a row of {} cells", width.max(1));
+                return Err(ParseError::new(&expected, line).offset_lines(y));
+            }
+
+            for (x, c) in line.chars().enumerate() {
+                let cell = mapper(c).ok_or_else(|| {
+                    ParseError::new(expected, &c.to_string())
+                        .offset_lines(y)
+                        .offset_columns(x)
+                })?;
+
+                cells.push(cell);
+            }
+
+            width = row_width;
+            height = y + 1;
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::new("at least one row", input));
+        }
+
+        Ok(Self::new(width, height, cells))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        match self.contains(x, y) {
+            true => self.cells.get(y * self.width + x),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        match self.contains(x, y) {
+            true => self.cells.get_mut(y * self.width + x),
+            false => None,
+        }
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        match y < self.height {
+            true => Some(&self.cells[y * self.width..(y + 1) * self.width]),
+            false => None,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(match x < self.width {
+                true => self.height,
+                false => 0,
+            })
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position2d<usize>> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position2d::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position2d<usize>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn neighbors_4(&self, x: usize, y: usize) -> impl Iterator<Item = Position2d<usize>> + '_ {
+        Position2d::new(x, y)
+            .neighbors_4()
+            .filter(|position| self.contains(position.x, position.y))
+    }
+
+    pub fn neighbors_8(&self, x: usize, y: usize) -> impl Iterator<Item = Position2d<usize>> + '_ {
+        Position2d::new(x, y)
+            .neighbors_8()
+            .filter(|position| self.contains(position.x, position.y))
+    }
+
+    pub fn map<U, F>(&self, mapper: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.iter().map(mapper).collect(),
+        )
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    fn rearranged<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y).into()].clone())
+            .collect();
+
+        Self::new(width, height, cells)
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.rearranged(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+
+        self.rearranged(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+
+        self.rearranged(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+}
+
+impl<T> Index<Position2d<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, Position2d { x, y }: Position2d<usize>) -> &Self::Output {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("position ({x}, {y}) is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position2d<usize>> for Grid<T> {
+    fn index_mut(&mut self, Position2d { x, y }: Position2d<usize>) -> &mut Self::Output {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("position ({x}, {y}) is outside of the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Grid<char> {
+        Grid::parse_with("abc\ndef", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn parses_character_grids() {
+        let grid = Grid::parse_with("12\n34\n56", "a digit", |c| c.to_digit(10)).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.get(1, 2), Some(&6));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid[Position2d::new(0, 1)], 3);
+    }
+
+    #[test]
+    fn rejects_invalid_grids() {
+        let error = Grid::parse_with("12\n3x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 2, "x")
+        );
+
+        let error = Grid::parse_with("12\n345", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a row of 2 cells")
+        );
+
+        assert!(Grid::parse_with("", "a digit", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = letters();
+
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.rows().count(), 2);
+
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn finds_neighbors_inside_the_grid() {
+        let grid = letters();
+
+        let neighbors: Vec<_> = grid.neighbors_4(0, 0).collect();
+        assert_eq!(
+            neighbors,
+            vec![Position2d::new(1, 0), Position2d::new(0, 1)]
+        );
+
+        let neighbors: Vec<_> = grid.neighbors_8(1, 0).collect();
+        let expected: Vec<_> = [(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]
+            .into_iter()
+            .map(Position2d::from)
+            .collect();
+        assert_eq!(neighbors, expected);
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = letters();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+    }
+
+    #[test]
+    fn maps_cells() {
+        let grid = letters().map(|c| c.is_ascii_lowercase() && *c > 'c');
+
+        assert_eq!(
+            grid.iter()
+                .filter(|(_, cell)| **cell)
+                .map(|(position, _)| position)
+                .collect::<Vec<_>>(),
+            vec![
+                Position2d::new(0, 1),
+                Position2d::new(1, 1),
+                Position2d::new(2, 1)
+            ]
+        );
+    }
+}
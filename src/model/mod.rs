@@ -1,5 +1,11 @@
 mod direction;
+mod grid;
 mod position;
+mod position_3d;
+mod voxels;
 
-pub use direction::Direction;
-pub use position::Position2d;
+pub use direction::{Direction, Direction8};
+pub use grid::Grid;
+pub use position::{Coordinate, Position2d};
+pub use position_3d::Position3d;
+pub use voxels::VoxelSet;
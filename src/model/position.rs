@@ -1 +1,267 @@
-pub type Position2d<T> = (T, T);
+use std::{
+    fmt,
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use super::{Direction, Direction8};
+
+pub trait Coordinate:
+    Copy + Ord + Hash + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn distance(self, other: Self) -> Self;
+    fn signum(self) -> Self;
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Option<Self>;
+}
+
+macro_rules! impl_signed_coordinate {
+    ($($t:ty),*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn distance(self, other: Self) -> Self {
+                (self - other).abs()
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Option<Self> {
+                value.try_into().ok()
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_unsigned_coordinate {
+    ($($t:ty),*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn distance(self, other: Self) -> Self {
+                self.abs_diff(other)
+            }
+
+            fn signum(self) -> Self {
+                self.min(1)
+            }
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(value: i128) -> Option<Self> {
+                value.try_into().ok()
+            }
+        }
+    )*};
+}
+
+impl_signed_coordinate!(i8, i16, i32, i64, i128, isize);
+impl_unsigned_coordinate!(u8, u16, u32, u64, usize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position2d<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Position2d<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(T::ZERO, T::ZERO)
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn convert<U: Coordinate>(&self) -> Option<Position2d<U>> {
+        Some(Position2d::new(
+            U::from_i128(self.x.to_i128())?,
+            U::from_i128(self.y.to_i128())?,
+        ))
+    }
+
+    pub fn checked_offset(&self, dx: i128, dy: i128) -> Option<Self> {
+        Some(Self::new(
+            T::from_i128(self.x.to_i128().checked_add(dx)?)?,
+            T::from_i128(self.y.to_i128().checked_add(dy)?)?,
+        ))
+    }
+
+    pub fn step(&self, direction: impl Into<Direction8>) -> Option<Self> {
+        let (dx, dy) = direction.into().offset();
+
+        self.checked_offset(dx, dy)
+    }
+
+    pub fn neighbors_4(&self) -> impl Iterator<Item = Self> {
+        let position = *self;
+
+        Direction::all()
+            .into_iter()
+            .filter_map(move |direction| position.step(direction))
+    }
+
+    pub fn neighbors_8(&self) -> impl Iterator<Item = Self> {
+        let position = *self;
+
+        Direction8::all()
+            .into_iter()
+            .filter_map(move |direction| position.step(direction))
+    }
+}
+
+impl<T: Coordinate> Add for Position2d<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Position2d<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> Sub for Position2d<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coordinate> SubAssign for Position2d<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Neg for Position2d<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Position2d<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T> From<(T, T)> for Position2d<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> From<Direction> for Position2d<T> {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Self::new(T::ZERO, -T::ONE),
+            Direction::Down => Self::new(T::ZERO, T::ONE),
+            Direction::Left => Self::new(-T::ONE, T::ZERO),
+            Direction::Right => Self::new(T::ONE, T::ZERO),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Position2d<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_vector_arithmetic() {
+        let a = Position2d::new(3, -2);
+        let b = Position2d::new(-1, 4);
+
+        assert_eq!(a + b, Position2d::new(2, 2));
+        assert_eq!(a - b, Position2d::new(4, -6));
+        assert_eq!(-a, Position2d::new(-3, 2));
+        assert_eq!(a * 3, Position2d::new(9, -6));
+        assert_eq!((a - b).signum(), Position2d::new(1, -1));
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Position2d::new(1_usize, 5);
+        let b = Position2d::new(4_usize, 1);
+
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(Position2d::new(-2, 3).manhattan(&Position2d::origin()), 5);
+    }
+
+    #[test]
+    fn converts_between_coordinate_types() {
+        assert_eq!(
+            Position2d::new(2_isize, 7).convert::<usize>(),
+            Some(Position2d::new(2_usize, 7))
+        );
+        assert_eq!(Position2d::new(-1_isize, 7).convert::<usize>(), None);
+        assert_eq!(Position2d::new(300_u32, 1).convert::<u8>(), None);
+    }
+
+    #[test]
+    fn enumerates_neighbors() {
+        let corner = Position2d::new(0_usize, 0);
+        let neighbors: Vec<_> = corner.neighbors_4().collect();
+        assert_eq!(
+            neighbors,
+            vec![Position2d::new(1, 0), Position2d::new(0, 1)]
+        );
+
+        assert_eq!(Position2d::new(0, 0).neighbors_8().count(), 8);
+    }
+
+    #[test]
+    fn steps_in_direction() {
+        let position = Position2d::new(0, 0) + Position2d::from(Direction::Up) * 2;
+
+        assert_eq!(position, Position2d::new(0, -2));
+        assert_eq!(
+            Position2d::new(5, 5).neighbors_4().next(),
+            Some(Position2d::new(5, 5) + Position2d::from(Direction::Up))
+        );
+        assert_eq!(
+            Position2d::<i32>::from(Direction::Left),
+            Position2d::new(-1, 0)
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use super::Coordinate;
+use crate::util::ParseError;
+
+const OFFSETS_6: [(i128, i128, i128); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position3d<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Position3d<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x
+            .distance(other.x)
+            .max(self.y.distance(other.y))
+            .max(self.z.distance(other.z))
+    }
+
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn component_min(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    pub fn component_max(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    pub fn convert<U: Coordinate>(&self) -> Option<Position3d<U>> {
+        Some(Position3d::new(
+            U::from_i128(self.x.to_i128())?,
+            U::from_i128(self.y.to_i128())?,
+            U::from_i128(self.z.to_i128())?,
+        ))
+    }
+
+    pub fn checked_offset(&self, dx: i128, dy: i128, dz: i128) -> Option<Self> {
+        Some(Self::new(
+            T::from_i128(self.x.to_i128().checked_add(dx)?)?,
+            T::from_i128(self.y.to_i128().checked_add(dy)?)?,
+            T::from_i128(self.z.to_i128().checked_add(dz)?)?,
+        ))
+    }
+
+    pub fn neighbors_6(&self) -> impl Iterator<Item = Self> {
+        let position = *self;
+
+        OFFSETS_6
+            .iter()
+            .filter_map(move |(dx, dy, dz)| position.checked_offset(*dx, *dy, *dz))
+    }
+
+    pub fn neighbors_26(&self) -> impl Iterator<Item = Self> {
+        let position = *self;
+
+        (-1..=1)
+            .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+            .filter(|offset| *offset != (0, 0, 0))
+            .filter_map(move |(dx, dy, dz)| position.checked_offset(dx, dy, dz))
+    }
+}
+
+impl<T: Coordinate> Add for Position3d<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Position3d<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> Sub for Position3d<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Coordinate> SubAssign for Position3d<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coordinate + Neg<Output = T>> Neg for Position3d<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Position3d<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Self::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl<T> From<(T, T, T)> for Position3d<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coordinate + FromStr> FromStr for Position3d<T> {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut coordinates = line.split(',');
+        let mut next = || {
+            let token = coordinates.next().ok_or_else(|| {
+                ParseError::in_line(
+                    line,
+                    &line[line.len()..],
+                    "three comma separated coordinates",
+                )
+            })?;
+
+            token
+                .trim()
+                .parse()
+                .map_err(|_| ParseError::in_line(line, token, "a coordinate"))
+        };
+
+        let position = Self::new(next()?, next()?, next()?);
+
+        match coordinates.next() {
+            Some(token) => Err(ParseError::in_line(line, token, "the end of the line")),
+            None => Ok(position),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Position3d<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_vector_arithmetic() {
+        let a = Position3d::new(1, -2, 3);
+        let b = Position3d::new(2, 2, -1);
+
+        assert_eq!(a + b, Position3d::new(3, 0, 2));
+        assert_eq!(a - b, Position3d::new(-1, -4, 4));
+        assert_eq!(-a * 2, Position3d::new(-2, 4, -6));
+        assert_eq!((a - b).signum(), Position3d::new(-1, -1, 1));
+        assert_eq!(a.manhattan(&b), 9);
+        assert_eq!(a.chebyshev(&b), 4);
+    }
+
+    #[test]
+    fn enumerates_neighbors() {
+        let position = Position3d::new(1, 1, 1);
+
+        assert_eq!(position.neighbors_6().count(), 6);
+        assert_eq!(position.neighbors_26().count(), 26);
+        assert!(position
+            .neighbors_6()
+            .all(|neighbor| neighbor.manhattan(&position) == 1));
+
+        assert_eq!(Position3d::new(0_u8, 0, 0).neighbors_6().count(), 3);
+        assert_eq!(Position3d::new(0_u8, 0, 0).neighbors_26().count(), 7);
+    }
+
+    #[test]
+    fn parses_positions() {
+        assert_eq!("2,-1,5".parse(), Ok(Position3d::new(2, -1, 5)));
+
+        let error = "2,x,5".parse::<Position3d<i32>>().unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (3, "x"));
+
+        assert!("2,1".parse::<Position3d<i32>>().is_err());
+        assert!("2,1,3,4".parse::<Position3d<i32>>().is_err());
+    }
+}
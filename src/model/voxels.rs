@@ -0,0 +1,175 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{Coordinate, Position3d};
+
+#[derive(Debug, Clone, Default)]
+pub struct VoxelSet<T> {
+    voxels: HashSet<Position3d<T>>,
+}
+
+impl<T: Coordinate> VoxelSet<T> {
+    pub fn new() -> Self {
+        Self {
+            voxels: HashSet::new(),
+        }
+    }
+
+    pub fn insert(&mut self, position: Position3d<T>) -> bool {
+        self.voxels.insert(position)
+    }
+
+    pub fn remove(&mut self, position: &Position3d<T>) -> bool {
+        self.voxels.remove(position)
+    }
+
+    pub fn contains(&self, position: &Position3d<T>) -> bool {
+        self.voxels.contains(position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.voxels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.voxels.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Position3d<T>> {
+        self.voxels.iter()
+    }
+}
+
+impl<T: Coordinate> VoxelSet<T> {
+    pub fn bounding_box(&self) -> Option<(Position3d<T>, Position3d<T>)> {
+        let first = *self.voxels.iter().next()?;
+
+        Some(
+            self.voxels
+                .iter()
+                .fold((first, first), |(min, max), voxel| {
+                    (min.component_min(voxel), max.component_max(voxel))
+                }),
+        )
+    }
+
+    pub fn flood_fill(
+        &self,
+        start: Position3d<T>,
+        min: Position3d<T>,
+        max: Position3d<T>,
+    ) -> HashSet<Position3d<T>> {
+        let inside = |position: &Position3d<T>| {
+            *position == position.component_max(&min).component_min(&max)
+                && !self.contains(position)
+        };
+        let mut filled = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        if inside(&start) {
+            filled.insert(start);
+            queue.push_back(start);
+        }
+
+        while let Some(position) = queue.pop_front() {
+            for neighbor in position.neighbors_6() {
+                if inside(&neighbor) && filled.insert(neighbor) {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        filled
+    }
+
+    pub fn surface_area(&self) -> usize {
+        self.voxels
+            .iter()
+            .map(|voxel| {
+                6 - voxel
+                    .neighbors_6()
+                    .filter(|neighbor| self.contains(neighbor))
+                    .count()
+            })
+            .sum()
+    }
+
+    pub fn exterior_surface_area(&self) -> usize {
+        let voxels: VoxelSet<i128> = self
+            .voxels
+            .iter()
+            .filter_map(|voxel| voxel.convert())
+            .collect();
+        let Some((min, max)) = voxels.bounding_box() else {
+            return 0;
+        };
+
+        let outside = Position3d::new(1, 1, 1);
+        let exterior = voxels.flood_fill(min - outside, min - outside, max + outside);
+
+        voxels
+            .iter()
+            .flat_map(|voxel| voxel.neighbors_6())
+            .filter(|neighbor| exterior.contains(neighbor))
+            .count()
+    }
+}
+
+impl<T: Coordinate> FromIterator<Position3d<T>> for VoxelSet<T> {
+    fn from_iter<I: IntoIterator<Item = Position3d<T>>>(positions: I) -> Self {
+        Self {
+            voxels: positions.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse_lines;
+
+    const DROPLET: &str =
+        "2,2,2\n1,2,2\n3,2,2\n2,1,2\n2,3,2\n2,2,1\n2,2,3\n2,2,4\n2,2,6\n1,2,5\n3,2,5\n2,1,5\n2,3,5";
+
+    fn droplet() -> VoxelSet<u8> {
+        parse_lines(DROPLET).unwrap().into_iter().collect()
+    }
+
+    #[test]
+    fn finds_bounding_box() {
+        assert_eq!(
+            droplet().bounding_box(),
+            Some((Position3d::new(1, 1, 1), Position3d::new(3, 3, 6)))
+        );
+        assert_eq!(VoxelSet::<u8>::new().bounding_box(), None);
+    }
+
+    #[test]
+    fn measures_surface_area() {
+        let pair: VoxelSet<i32> = [Position3d::new(1, 1, 1), Position3d::new(2, 1, 1)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(pair.surface_area(), 10);
+        assert_eq!(droplet().surface_area(), 64);
+    }
+
+    #[test]
+    fn measures_exterior_surface_area() {
+        assert_eq!(droplet().exterior_surface_area(), 58);
+
+        let corner: VoxelSet<u8> = [Position3d::new(0, 0, 0)].into_iter().collect();
+        assert_eq!(corner.exterior_surface_area(), 6);
+    }
+
+    #[test]
+    fn flood_fills_enclosed_space() {
+        let droplet = droplet();
+        let cavity = droplet.flood_fill(
+            Position3d::new(2, 2, 5),
+            Position3d::new(1, 1, 1),
+            Position3d::new(3, 3, 6),
+        );
+
+        assert_eq!(cavity.len(), 1);
+    }
+}
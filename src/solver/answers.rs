@@ -0,0 +1,229 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path, str::FromStr};
+
+use super::{Answer, Part};
+use crate::util::ParseError;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AnswerSheet {
+    answers: BTreeMap<(u8, Part), Answer>,
+}
+
+impl AnswerSheet {
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: Answer) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl AnswerSheet {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(path)
+            .map_err(|error| format!("could not read '{}': {error}", path.display()))?;
+
+        text.parse()
+            .map_err(|error| format!("could not parse '{}', {error}", path.display()))
+    }
+
+    pub fn parse_day(day: u8, input: &str) -> Result<Self, ParseError> {
+        parse_sheet(input, Some(day))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string())
+            .map_err(|error| format!("could not write '{}': {error}", path.display()))
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut result = String::new();
+
+    for character in text.chars() {
+        match character {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            character => result.push(character),
+        }
+    }
+
+    result
+}
+
+fn unescape(line: &str, text: &str) -> Result<String, ParseError> {
+    let mut result = String::new();
+    let mut characters = text.chars();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => match characters.next() {
+                Some('\\') => result.push('\\'),
+                Some('"') => result.push('"'),
+                Some('n') => result.push('\n'),
+                _ => return Err(ParseError::in_line(line, text, "a valid escape sequence")),
+            },
+            character => result.push(character),
+        }
+    }
+
+    Ok(result)
+}
+
+fn parse_answer(line: &str, value: &str) -> Result<Answer, ParseError> {
+    if let Some(text) = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    {
+        return Ok(Answer::Text(unescape(line, text)?));
+    }
+
+    value
+        .parse()
+        .map(Answer::Number)
+        .map_err(|_| ParseError::in_line(line, value, "a number or a quoted string"))
+}
+
+fn parse_number<T: FromStr>(line: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::in_line(line, token, expected))
+}
+
+fn parse_sheet(input: &str, mut day: Option<u8>) -> Result<AnswerSheet, ParseError> {
+    let mut sheet = AnswerSheet::default();
+
+    for (index, line) in input.lines().enumerate() {
+        let error_at = |error: ParseError| error.offset_lines(index);
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some(section) = trimmed.strip_prefix("[day_") {
+            let number = section
+                .strip_suffix(']')
+                .ok_or_else(|| error_at(ParseError::in_line(line, trimmed, "[day_XX]")))?;
+
+            day = Some(parse_number(line, number, "a day number").map_err(error_at)?);
+            continue;
+        }
+
+        let (key, value) = trimmed
+            .split_once('=')
+            .ok_or_else(|| error_at(ParseError::in_line(line, trimmed, "part_N = answer")))?;
+        let (key, value) = (key.trim(), value.trim());
+
+        let part = match key {
+            "part_1" => Part::One,
+            "part_2" => Part::Two,
+            _ => return Err(error_at(ParseError::in_line(line, key, "part_1 or part_2"))),
+        };
+        let day = day
+            .ok_or_else(|| error_at(ParseError::in_line(line, key, "a [day_XX] section first")))?;
+
+        sheet.insert(day, part, parse_answer(line, value).map_err(error_at)?);
+    }
+
+    Ok(sheet)
+}
+
+impl FromStr for AnswerSheet {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_sheet(input, None)
+    }
+}
+
+impl fmt::Display for AnswerSheet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut current_day = None;
+
+        for ((day, part), answer) in &self.answers {
+            if current_day != Some(*day) {
+                if current_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day_{:02}]", day)?;
+                current_day = Some(*day);
+            }
+
+            match answer {
+                Answer::Number(number) => writeln!(f, "part_{part} = {number}")?,
+                Answer::Text(text) => writeln!(f, "part_{part} = \"{}\"", escape(text))?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answer_sheet() {
+        let input = "# recorded answers\n[day_01]\npart_1 = 24000\npart_2 = 45000\n\n[day_05]\npart_1 = \"CMZ\"";
+
+        let sheet: AnswerSheet = input.parse().unwrap();
+
+        assert_eq!(sheet.len(), 3);
+        assert_eq!(sheet.get(1, Part::Two), Some(&Answer::Number(45000)));
+        assert_eq!(
+            sheet.get(5, Part::One),
+            Some(&Answer::Text("CMZ".to_string()))
+        );
+        assert_eq!(sheet.get(5, Part::Two), None);
+    }
+
+    #[test]
+    fn round_trips_multi_line_answers() {
+        let mut sheet = AnswerSheet::default();
+        sheet.insert(10, Part::Two, Answer::Text("\n##..\n\"#\\".to_string()));
+        sheet.insert(10, Part::One, Answer::Number(-13140));
+        sheet.insert(2, Part::One, Answer::Number(15));
+
+        let text = sheet.to_string();
+        assert_eq!(
+            text,
+            "[day_02]\npart_1 = 15\n\n[day_10]\npart_1 = -13140\npart_2 = \"\\n##..\\n\\\"#\\\\\"\n"
+        );
+        assert_eq!(text.parse(), Ok(sheet));
+    }
+
+    #[test]
+    fn parses_answers_for_a_single_day() {
+        let sheet = AnswerSheet::parse_day(6, "part_1 = 7\npart_2 = 19").unwrap();
+
+        assert_eq!(sheet.get(6, Part::One), Some(&Answer::Number(7)));
+        assert_eq!(sheet.get(6, Part::Two), Some(&Answer::Number(19)));
+    }
+
+    #[test]
+    fn rejects_invalid_answer_sheets() {
+        let error = "part_1 = 3".parse::<AnswerSheet>().unwrap_err();
+        assert_eq!(error.line, 1);
+
+        let error = "[day_01]\npart_3 = 3".parse::<AnswerSheet>().unwrap_err();
+        assert_eq!((error.line, error.token.as_str()), (2, "part_3"));
+
+        let error = "[day_01]\npart_1 = CMZ".parse::<AnswerSheet>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 10));
+    }
+}
@@ -0,0 +1,87 @@
+mod answer;
+mod answers;
+mod part;
+mod registry;
+mod run;
+
+pub use answer::Answer;
+pub use answers::AnswerSheet;
+pub use part::Part;
+pub use registry::{find_puzzle, registry};
+pub use run::{Run, Solution};
+
+use std::time::Instant;
+
+use crate::util::{ParseError, Rng};
+
+pub trait Solver {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn solve_part_1(input: &Self::Input) -> Answer;
+
+    fn solve_part_2(input: &Self::Input) -> Answer;
+
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    fn report(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError>;
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+
+    fn report(&self, input: &str) -> Result<Option<String>, ParseError>;
+}
+
+impl<S: Solver + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+        let start = Instant::now();
+        let input = S::parse(input)?;
+        let parse_elapsed = start.elapsed();
+
+        let solutions = parts
+            .iter()
+            .map(|part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::solve_part_1(&input),
+                    Part::Two => S::solve_part_2(&input),
+                };
+
+                Solution {
+                    part: *part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(Run {
+            parse_elapsed,
+            solutions,
+        })
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn report(&self, input: &str) -> Result<Option<String>, ParseError> {
+        Ok(S::report(&S::parse(input)?))
+    }
+}
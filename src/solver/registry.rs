@@ -0,0 +1,35 @@
+use super::Puzzle;
+use crate::{
+    day_01::Day01, day_02::Day02, day_03::Day03, day_04::Day04, day_05::Day05, day_06::Day06,
+    day_07::Day07, day_08::Day08, day_09::Day09, day_10::Day10,
+};
+
+pub fn registry() -> Vec<&'static dyn Puzzle> {
+    vec![
+        &Day01, &Day02, &Day03, &Day04, &Day05, &Day06, &Day07, &Day08, &Day09, &Day10,
+    ]
+}
+
+pub fn find_puzzle(day: u8) -> Option<&'static dyn Puzzle> {
+    registry().into_iter().find(|puzzle| puzzle.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_days_in_order() {
+        let days: Vec<u8> = registry().iter().map(|puzzle| puzzle.day()).collect();
+
+        assert_eq!(days.first(), Some(&1));
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(days.iter().all(|day| (1..=25).contains(day)));
+    }
+
+    #[test]
+    fn finds_puzzle_by_day() {
+        assert_eq!(find_puzzle(7).map(|puzzle| puzzle.day()), Some(7));
+        assert!(find_puzzle(25).is_none());
+    }
+}
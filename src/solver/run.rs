@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use super::{Answer, Part};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub solutions: Vec<Solution>,
+}
+
+impl Run {
+    pub fn answers(&self) -> Vec<(Part, Answer)> {
+        self.solutions
+            .iter()
+            .map(|solution| (solution.part, solution.answer.clone()))
+            .collect()
+    }
+
+    pub fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed
+            + self
+                .solutions
+                .iter()
+                .map(|solution| solution.elapsed)
+                .sum::<Duration>()
+    }
+}
@@ -0,0 +1,333 @@
+use std::str::FromStr;
+
+use super::ParseError;
+
+pub struct Scanner<'a> {
+    source: &'a str,
+    offset: usize,
+    end: usize,
+}
+
+pub trait Parser<'a, T>: Fn(&mut Scanner<'a>) -> Result<T, ParseError> {}
+
+impl<'a, T, F> Parser<'a, T> for F where F: Fn(&mut Scanner<'a>) -> Result<T, ParseError> {}
+
+impl<'a> Scanner<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            offset: 0,
+            end: source.len(),
+        }
+    }
+
+    fn bounded(&self, end: usize) -> Self {
+        Self {
+            source: self.source,
+            offset: self.offset,
+            end,
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.offset..self.end]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.offset == self.end
+    }
+
+    fn advance(&mut self, length: usize) -> &'a str {
+        let consumed = &self.rest()[..length];
+        self.offset += length;
+        consumed
+    }
+
+    pub fn token(&self) -> &'a str {
+        let rest = self.rest().trim_start();
+        let length = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+        &rest[..length]
+    }
+
+    pub fn error_at(&self, token: &str, expected: &str) -> ParseError {
+        let offset = token.as_ptr() as usize - self.source.as_ptr() as usize;
+        let before = &self.source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        ParseError::new(expected, token)
+            .offset_lines(before.matches('\n').count())
+            .offset_columns(offset - line_start)
+    }
+
+    pub fn fail(&self, expected: &str) -> ParseError {
+        self.error_at(self.token(), expected)
+    }
+}
+
+fn complete<'a, T>(
+    scanner: &mut Scanner<'a>,
+    parser: &impl Parser<'a, T>,
+) -> Result<T, ParseError> {
+    let value = parser(scanner)?;
+
+    match scanner.is_at_end() {
+        true => Ok(value),
+        false => Err(scanner.fail("the end of the input")),
+    }
+}
+
+pub fn parse_all<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    complete(&mut Scanner::new(input), &parser)
+}
+
+fn number<'a, T: FromStr>(signed: bool, expected: &'static str) -> impl Parser<'a, T> {
+    move |scanner: &mut Scanner<'a>| {
+        let rest = scanner.rest();
+        let sign = match signed && rest.starts_with(['-', '+']) {
+            true => 1,
+            false => 0,
+        };
+        let length = rest[sign..]
+            .find(|character: char| !character.is_alphanumeric())
+            .map_or(rest.len(), |length| sign + length);
+
+        match length {
+            0 => Err(scanner.fail(expected)),
+            _ => {
+                let token = &rest[..length];
+                let value = token
+                    .parse()
+                    .map_err(|_| scanner.error_at(token, expected))?;
+                scanner.advance(length);
+
+                Ok(value)
+            }
+        }
+    }
+}
+
+pub fn unsigned<'a, T: FromStr>() -> impl Parser<'a, T> {
+    number(false, "a number")
+}
+
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, T> {
+    number(true, "a signed number")
+}
+
+pub fn literal<'a>(text: &'static str) -> impl Parser<'a, &'a str> {
+    move |scanner: &mut Scanner<'a>| match scanner.rest().starts_with(text) {
+        true => Ok(scanner.advance(text.len())),
+        false => Err(scanner.fail(&format!("'{}'", text.trim()))),
+    }
+}
+
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |scanner: &mut Scanner<'a>| {
+        let rest = scanner.rest();
+
+        match rest.find(char::is_whitespace).unwrap_or(rest.len()) {
+            0 => Err(scanner.fail("a word")),
+            length => Ok(scanner.advance(length)),
+        }
+    }
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, mapper: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |scanner: &mut Scanner<'a>| parser(scanner).map(&mapper)
+}
+
+pub fn consumed<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, (T, &'a str)> {
+    move |scanner: &mut Scanner<'a>| {
+        let start = scanner.offset;
+        let value = parser(scanner)?;
+
+        Ok((value, &scanner.source[start..scanner.offset]))
+    }
+}
+
+pub fn expecting<'a, T>(expected: &'static str, parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |scanner: &mut Scanner<'a>| {
+        parser(scanner).map_err(|error| ParseError {
+            expected: expected.to_string(),
+            ..error
+        })
+    }
+}
+
+pub fn preceded<'a, T>(prefix: &'static str, parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |scanner: &mut Scanner<'a>| {
+        literal(prefix)(scanner)?;
+        parser(scanner)
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    separator: &'static str,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |scanner: &mut Scanner<'a>| {
+        let first = first(scanner)?;
+        literal(separator)(scanner)?;
+
+        Ok((first, second(scanner)?))
+    }
+}
+
+pub fn key_value<'a, T>(
+    separator: &'static str,
+    value: impl Parser<'a, T>,
+) -> impl Parser<'a, (&'a str, T)> {
+    move |scanner: &mut Scanner<'a>| {
+        let rest = scanner.rest();
+        let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+
+        match line.find(separator) {
+            Some(0) | None => Err(scanner.fail(&format!("a key followed by '{separator}'"))),
+            Some(length) => {
+                let key = scanner.advance(length);
+                scanner.advance(separator.len());
+
+                Ok((key, value(scanner)?))
+            }
+        }
+    }
+}
+
+pub fn separated<'a, T>(
+    item: impl Parser<'a, T>,
+    separator: &'static str,
+) -> impl Parser<'a, Vec<T>> {
+    move |scanner: &mut Scanner<'a>| {
+        let mut items = vec![item(scanner)?];
+
+        while scanner.rest().starts_with(separator) {
+            scanner.advance(separator.len());
+            items.push(item(scanner)?);
+        }
+
+        Ok(items)
+    }
+}
+
+fn delimited<'a, T>(
+    scanner: &mut Scanner<'a>,
+    item: &impl Parser<'a, T>,
+    delimiter: &str,
+) -> Result<Vec<T>, ParseError> {
+    let mut items = vec![];
+
+    while !scanner.is_at_end() {
+        let end = scanner
+            .rest()
+            .find(delimiter)
+            .map_or(scanner.end, |length| scanner.offset + length);
+
+        items.push(complete(&mut scanner.bounded(end), item)?);
+        scanner.offset = end;
+
+        if !scanner.is_at_end() {
+            scanner.advance(delimiter.len());
+        }
+    }
+
+    Ok(items)
+}
+
+pub fn lines<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |scanner: &mut Scanner<'a>| delimited(scanner, &item, "\n")
+}
+
+pub fn blocks<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |scanner: &mut Scanner<'a>| delimited(scanner, &item, "\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(parse_all("42", unsigned::<u32>()), Ok(42));
+        assert_eq!(parse_all("-42", signed::<i32>()), Ok(-42));
+        assert_eq!(parse_all("+7", signed::<i32>()), Ok(7));
+
+        let error = parse_all("-42", unsigned::<u32>()).unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (1, "-42"));
+
+        let error = parse_all("4x2", unsigned::<u32>()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a number, found '4x2'"
+        );
+
+        let error = parse_all("300", unsigned::<u8>()).unwrap_err();
+        assert_eq!(error.token, "300");
+    }
+
+    #[test]
+    fn parses_sequences() {
+        let parser = preceded("move ", pair(unsigned::<u8>(), " to ", word()));
+        assert_eq!(parse_all("move 3 to top", parser), Ok((3, "top")));
+
+        let parser = separated(signed::<i32>(), ", ");
+        assert_eq!(parse_all("1, -2, 3", &parser), Ok(vec![1, -2, 3]));
+
+        let parser = separated(consumed(unsigned::<u8>()), ",");
+        assert_eq!(parse_all("7,008", parser), Ok(vec![(7, "7"), (8, "008")]));
+
+        let parser = separated(signed::<i32>(), ", ");
+        let error = parse_all("1, -2, x", &parser).unwrap_err();
+        assert_eq!((error.column, error.token.as_str()), (8, "x"));
+
+        let error = parse_all("1, 2 3", &parser).unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (6, "the end of the input")
+        );
+    }
+
+    #[test]
+    fn parses_key_values() {
+        let parser = lines(key_value(": ", separated(unsigned::<u8>(), ", ")));
+
+        assert_eq!(
+            parse_all("Starting items: 79, 98\nTest: 23", &parser),
+            Ok(vec![("Starting items", vec![79, 98]), ("Test", vec![23])])
+        );
+
+        let error = parse_all("Test 23", &parser).unwrap_err();
+        assert_eq!(error.expected, "a key followed by ': '");
+    }
+
+    #[test]
+    fn parses_blocks_of_lines() {
+        let parser = blocks(lines(expecting("a calorie count", unsigned::<u32>())));
+
+        assert_eq!(
+            parse_all("1\n2\n\n3\n", &parser),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+
+        let error = parse_all("1\n2\n\n3\n4y", &parser).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(error.expected, "a calorie count");
+        assert_eq!(error.token, "4y");
+    }
+
+    #[test]
+    fn reports_missing_input() {
+        let error = parse_all("mv 3", literal("move ")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected 'move', found 'mv'"
+        );
+
+        let error = parse_all("move ", preceded("move ", unsigned::<u8>())).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected a number, found nothing"
+        );
+    }
+}
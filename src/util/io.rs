@@ -1,7 +1,156 @@
-use std::fs::read_to_string;
+use std::{
+    env, error, fmt,
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
 
-pub fn read_input(file_path: &str) -> String {
-    let text = read_to_string(file_path).unwrap();
+const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+const INPUT_USER_VAR: &str = "AOC_USER";
+pub const STDIN_PATH: &str = "-";
 
-    text
-}
\ No newline at end of file
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u8,
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "could not read input for day {} from '{}': {}",
+            self.day,
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn read_stdin() -> io::Result<String> {
+    let mut input = String::new();
+    io::stdin().lock().read_to_string(&mut input)?;
+
+    Ok(input)
+}
+
+pub fn read_input(day: u8, file_path: &Path) -> Result<String, InputError> {
+    let input = match file_path == Path::new(STDIN_PATH) {
+        true => read_stdin(),
+        false => read_to_string(file_path),
+    };
+
+    input.map_err(|source| InputError {
+        day,
+        path: file_path.to_path_buf(),
+        source,
+    })
+}
+
+fn default_inputs_dir() -> PathBuf {
+    let manifest_inputs = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+
+    if manifest_inputs.is_dir() {
+        return manifest_inputs;
+    }
+
+    env::current_exe()
+        .ok()
+        .and_then(|executable| executable.parent().map(|dir| dir.join("inputs")))
+        .unwrap_or(manifest_inputs)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputResolver {
+    inputs_dir: PathBuf,
+    user: Option<String>,
+}
+
+impl InputResolver {
+    pub fn new(inputs_dir: PathBuf, user: Option<String>) -> Self {
+        Self { inputs_dir, user }
+    }
+
+    pub fn from_env(inputs_dir: Option<PathBuf>, user: Option<String>) -> Self {
+        let inputs_dir = inputs_dir
+            .or_else(|| env::var_os(INPUTS_DIR_VAR).map(PathBuf::from))
+            .unwrap_or_else(default_inputs_dir);
+        let user = user.or_else(|| env::var(INPUT_USER_VAR).ok());
+
+        Self::new(inputs_dir, user)
+    }
+}
+
+impl InputResolver {
+    pub fn inputs_dir(&self) -> PathBuf {
+        match &self.user {
+            Some(user) => self.inputs_dir.join(user),
+            None => self.inputs_dir.clone(),
+        }
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.inputs_dir().join(format!("day_{:02}", day))
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.day_dir(day).join("input.txt")
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        read_input(day, &self.input_path(day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_input_paths() {
+        let resolver = InputResolver::new(PathBuf::from("inputs"), None);
+        assert_eq!(
+            resolver.input_path(7),
+            PathBuf::from("inputs/day_07/input.txt")
+        );
+
+        let resolver = InputResolver::new(PathBuf::from("inputs"), Some("alice".to_string()));
+        assert_eq!(
+            resolver.input_path(10),
+            PathBuf::from("inputs/alice/day_10/input.txt")
+        );
+    }
+
+    #[test]
+    fn prefers_explicit_inputs_dir() {
+        let resolver = InputResolver::from_env(Some(PathBuf::from("elsewhere")), None);
+
+        assert!(resolver.input_path(1).starts_with("elsewhere"));
+    }
+
+    #[test]
+    fn reads_inputs_regardless_of_working_directory() {
+        let resolver = InputResolver::new(default_inputs_dir(), None);
+
+        assert!(resolver.read(1).is_ok());
+    }
+
+    #[test]
+    fn names_missing_file_and_day() {
+        let resolver = InputResolver::new(PathBuf::from("missing"), None);
+        let error = resolver.read(4).unwrap_err();
+
+        assert_eq!(error.day, 4);
+        assert_eq!(error.path, PathBuf::from("missing/day_04/input.txt"));
+        assert!(error
+            .to_string()
+            .starts_with("could not read input for day 4 from 'missing/day_04/input.txt'"));
+    }
+}
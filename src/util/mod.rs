@@ -1,5 +1,15 @@
+pub mod combinators;
 mod io;
+mod json;
+mod parse;
+mod pool;
+mod random;
+pub mod search;
 mod string;
 
-pub use io::read_input;
+pub use io::{read_input, InputError, InputResolver, STDIN_PATH};
+pub use json::Json;
+pub use parse::{parse_lines, ParseError};
+pub use pool::{default_workers, run_parallel};
+pub use random::Rng;
 pub use string::split_string;
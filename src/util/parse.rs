@@ -0,0 +1,120 @@
+use std::{error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+fn column_of(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let position = token.as_ptr() as usize;
+
+    match position >= start && position <= start + line.len() {
+        true => position - start + 1,
+        false => 1,
+    }
+}
+
+impl ParseError {
+    pub fn new(expected: &str, token: &str) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            token: token.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    pub fn in_line(line: &str, token: &str, expected: &str) -> Self {
+        Self {
+            column: column_of(line, token),
+            ..Self::new(expected, token)
+        }
+    }
+
+    pub fn offset_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    pub fn offset_columns(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+
+        match self.token.is_empty() {
+            true => write!(f, "found nothing"),
+            false => write!(f, "found '{}'", self.token),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|error: ParseError| error.offset_lines(index))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Number(u32);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(line: &str) -> Result<Self, Self::Err> {
+            let token = line.trim_start();
+
+            token
+                .parse()
+                .map(Number)
+                .map_err(|_| ParseError::in_line(line, token, "a number"))
+        }
+    }
+
+    #[test]
+    fn parses_lines() {
+        assert_eq!(
+            parse_lines::<Number>("1\n2\n3"),
+            Ok(vec![Number(1), Number(2), Number(3)])
+        );
+    }
+
+    #[test]
+    fn reports_line_and_column() {
+        let error = parse_lines::<Number>("1\n2\n   x").unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 4);
+        assert_eq!(error.token, "x");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: expected a number, found 'x'"
+        );
+    }
+}
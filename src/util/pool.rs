@@ -0,0 +1,95 @@
+use std::{
+    any::Any,
+    num::NonZeroUsize,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{mpsc, Mutex},
+    thread,
+};
+
+pub fn default_workers() -> usize {
+    thread::available_parallelism()
+        .map(NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+pub fn run_parallel<T, R, F>(jobs: Vec<T>, workers: usize, task: F) -> Vec<Result<R, String>>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let count = jobs.len();
+    let (job_sender, job_receiver) = mpsc::channel();
+    let (result_sender, result_receiver) = mpsc::channel();
+
+    for job in jobs.into_iter().enumerate() {
+        job_sender.send(job).unwrap();
+    }
+    drop(job_sender);
+
+    let job_receiver = Mutex::new(job_receiver);
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, count.max(1)) {
+            let result_sender = result_sender.clone();
+            let job_receiver = &job_receiver;
+            let task = &task;
+
+            scope.spawn(move || loop {
+                let next = job_receiver.lock().unwrap().recv();
+                let Ok((index, job)) = next else {
+                    break;
+                };
+
+                let result = catch_unwind(AssertUnwindSafe(|| task(job))).map_err(panic_message);
+                result_sender.send((index, result)).unwrap();
+            });
+        }
+    });
+    drop(result_sender);
+
+    let mut results: Vec<(usize, Result<R, String>)> = result_receiver.into_iter().collect();
+    results.sort_by_key(|(index, _)| *index);
+
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_results_in_job_order() {
+        let jobs: Vec<u64> = (0..20).collect();
+        let results = run_parallel(jobs, 4, |job| {
+            thread::sleep(std::time::Duration::from_micros(20 - job));
+            job * job
+        });
+
+        let expected: Vec<Result<u64, String>> = (0..20).map(|job| Ok(job * job)).collect();
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn isolates_panicking_jobs() {
+        let results = run_parallel(vec![1, 0, 2], 2, |job| match job {
+            0 => panic!("job {job} failed"),
+            _ => 10 / job,
+        });
+
+        assert_eq!(
+            results,
+            vec![Ok(10), Err("job 0 failed".to_string()), Ok(5)]
+        );
+    }
+}
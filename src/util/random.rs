@@ -0,0 +1,88 @@
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "bound must be positive");
+
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        let span = (end - start) as u128 + 1;
+
+        start + ((self.next_u64() as u128 * span) >> 64) as i64
+    }
+
+    pub fn chance(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_deterministic_per_seed() {
+        let (mut first, mut second) = (Rng::new(7), Rng::new(7));
+
+        for _ in 0..4 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+        assert_eq!(Rng::new(1234567).next_u64(), 6457827717110365317);
+    }
+
+    #[test]
+    fn stays_within_bounds() {
+        let mut rng = Rng::new(42);
+
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-2..=2).contains(&rng.range(-2..=2)));
+        }
+
+        assert_eq!(rng.range(5..=5), 5);
+        assert!((0..100).all(|_| !rng.chance(0, 4)));
+    }
+
+    #[test]
+    fn shuffles_in_place() {
+        let mut items: Vec<u32> = (0..20).collect();
+        Rng::new(3).shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<u32>>());
+
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<u32>>());
+    }
+}
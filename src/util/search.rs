@@ -0,0 +1,247 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::model::{Grid, Position2d};
+
+pub trait Graph {
+    type State: Clone + Eq + Hash;
+
+    fn neighbors(&self, state: &Self::State) -> Vec<Self::State>;
+
+    fn cost(&self, _from: &Self::State, _to: &Self::State) -> u64 {
+        1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+fn reconstruct_path<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+
+    while let Some(parent) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+
+    states.reverse();
+    states
+}
+
+pub fn bfs<G, F>(graph: &G, start: G::State, is_goal: F) -> Option<Path<G::State>>
+where
+    G: Graph,
+    F: Fn(&G::State) -> bool,
+{
+    let mut parents = HashMap::new();
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state];
+
+        if is_goal(&state) {
+            return Some(Path {
+                cost: distance,
+                states: reconstruct_path(&parents, state),
+            });
+        }
+
+        for next in graph.neighbors(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn a_star<G, F, H>(
+    graph: &G,
+    start: G::State,
+    is_goal: F,
+    heuristic: H,
+) -> Option<Path<G::State>>
+where
+    G: Graph,
+    F: Fn(&G::State) -> bool,
+    H: Fn(&G::State) -> u64,
+{
+    let mut parents = HashMap::new();
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut states = vec![start];
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let state = states[index].clone();
+
+        if cost > costs[&state] {
+            continue;
+        }
+
+        if is_goal(&state) {
+            return Some(Path {
+                cost,
+                states: reconstruct_path(&parents, state),
+            });
+        }
+
+        for next in graph.neighbors(&state) {
+            let next_cost = cost + graph.cost(&state, &next);
+
+            if costs.get(&next).is_none_or(|known| next_cost < *known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), state.clone());
+                queue.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+pub fn dijkstra<G, F>(graph: &G, start: G::State, is_goal: F) -> Option<Path<G::State>>
+where
+    G: Graph,
+    F: Fn(&G::State) -> bool,
+{
+    a_star(graph, start, is_goal, |_| 0)
+}
+
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    passable: F,
+}
+
+impl<'a, T, F> GridGraph<'a, T, F>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    pub fn new(grid: &'a Grid<T>, passable: F) -> Self {
+        Self { grid, passable }
+    }
+}
+
+impl<T, F> Graph for GridGraph<'_, T, F>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    type State = Position2d<usize>;
+
+    fn neighbors(&self, state: &Self::State) -> Vec<Self::State> {
+        let from = &self.grid[*state];
+
+        self.grid
+            .neighbors_4(state.x, state.y)
+            .filter(|next| (self.passable)(from, &self.grid[*next]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "S..#....\n.#.#.##.\n.#...#..\n.####.#.\n......#E";
+
+    fn maze() -> Grid<char> {
+        Grid::parse_with(MAZE, "a maze tile", Some).unwrap()
+    }
+
+    fn find(grid: &Grid<char>, tile: char) -> Position2d<usize> {
+        grid.iter()
+            .find(|(_, cell)| **cell == tile)
+            .map(|(position, _)| position)
+            .unwrap()
+    }
+
+    struct Weighted;
+
+    impl Graph for Weighted {
+        type State = char;
+
+        fn neighbors(&self, state: &char) -> Vec<char> {
+            match state {
+                'a' => vec!['b', 'c'],
+                'b' => vec!['d'],
+                'c' => vec!['d'],
+                _ => vec![],
+            }
+        }
+
+        fn cost(&self, from: &char, to: &char) -> u64 {
+            match (from, to) {
+                ('a', 'b') => 1,
+                ('b', 'd') => 10,
+                ('a', 'c') => 4,
+                _ => 2,
+            }
+        }
+    }
+
+    #[test]
+    fn finds_shortest_path_in_grid() {
+        let grid = maze();
+        let (start, end) = (find(&grid, 'S'), find(&grid, 'E'));
+        let graph = GridGraph::new(&grid, |_, to| *to != '#');
+
+        let path = bfs(&graph, start, |state| *state == end).unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.states.len(), 16);
+        assert_eq!((path.states[0], path.states[15]), (start, end));
+        assert!(path
+            .states
+            .windows(2)
+            .all(|step| step[0].manhattan(&step[1]) == 1 && grid[step[1]] != '#'));
+
+        let path = dijkstra(&graph, start, |state| *state == end).unwrap();
+        assert_eq!(path.cost, 15);
+
+        let path = a_star(
+            &graph,
+            start,
+            |state| *state == end,
+            |state| state.manhattan(&end) as u64,
+        );
+        assert_eq!(path.unwrap().cost, 15);
+    }
+
+    #[test]
+    fn weighs_edges() {
+        let path = dijkstra(&Weighted, 'a', |state| *state == 'd').unwrap();
+        assert_eq!(
+            path,
+            Path {
+                cost: 6,
+                states: vec!['a', 'c', 'd']
+            }
+        );
+
+        let path = bfs(&Weighted, 'a', |state| *state == 'd').unwrap();
+        assert_eq!(path.cost, 2);
+    }
+
+    #[test]
+    fn reports_unreachable_goals() {
+        let grid = maze();
+        let graph = GridGraph::new(&grid, |_, to| *to == '.');
+
+        let end = find(&grid, 'E');
+
+        assert_eq!(bfs(&graph, find(&grid, 'S'), |state| *state == end), None);
+        assert_eq!(dijkstra(&Weighted, 'd', |state| *state == 'a'), None);
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+fn run_with_stdin(arguments: &[&str], input: &str) -> (bool, String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_advent_of_code_2024"))
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+
+    let output = child.wait_with_output().unwrap();
+
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn reads_input_from_stdin() {
+    let (success, stdout, _) = run_with_stdin(
+        &["--day", "6", "--input", "-"],
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb",
+    );
+
+    assert!(success);
+    assert_eq!(stdout, "day 6\npart 1: 7\npart 2: 19\n");
+}
+
+#[test]
+fn reports_parse_errors_for_stdin() {
+    let (success, _, stderr) = run_with_stdin(&["--day", "1", "--input", "-"], "100\nabc\n");
+
+    assert!(!success);
+    assert!(stderr.contains("in '-', line 2, column 1"));
+}
+
+#[test]
+fn solves_generated_input() {
+    let (success, input, stderr) = run_with_stdin(&["generate", "4", "--seed", "1"], "");
+
+    assert!(success);
+    assert_eq!(stderr, "seed 1\n");
+    assert_eq!(input.lines().count(), 100);
+
+    let (success, stdout, _) = run_with_stdin(&["--day", "4", "--input", "-"], &input);
+
+    assert!(success);
+    assert!(stdout.starts_with("day 4\npart 1: "));
+}
+
+#[test]
+fn prints_calorie_report() {
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    let (success, stdout, _) = run_with_stdin(&["report", "1", "--input", "-"], input);
+
+    assert!(success);
+    assert!(stdout.starts_with("count           5\n"));
+    assert!(stdout.contains("\ntop elves\nelf 4: 24000 calories in 3 items (largest 9000)\n"));
+
+    let (success, _, stderr) = run_with_stdin(&["report", "2", "--input", "-"], "A Y\n");
+
+    assert!(!success);
+    assert_eq!(stderr, "day 2 has no report\n");
+}
+
+#[test]
+fn fails_verification_without_answers_file() {
+    let answers = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+    let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    let arguments = ["verify", "--day", "6", "--input", "-", "--answers"];
+    let answers_argument = answers.to_str().unwrap();
+
+    let (success, _, stderr) =
+        run_with_stdin(&[&arguments[..], &[answers_argument]].concat(), input);
+    assert!(!success);
+    assert!(stderr.contains("does not exist, create it with --record"));
+
+    std::fs::write(&answers, "[day_06]\npart_1 = 7\n").unwrap();
+
+    let (success, stdout, _) =
+        run_with_stdin(&[&arguments[..], &[answers_argument]].concat(), input);
+    assert!(success);
+    assert!(stdout.contains("1 passed, 0 failed, 1 unrecorded, 0 errors"));
+
+    let (success, _, _) = run_with_stdin(
+        &[&arguments[..], &[answers_argument, "--strict"]].concat(),
+        input,
+    );
+    assert!(!success);
+
+    std::fs::remove_file(answers).unwrap();
+}
+
+#[test]
+fn rejects_invalid_interactive_day() {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent_of_code_2024"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .and_then(|mut child| {
+            let _ = child.stdin.take().unwrap().write_all(b"seven\n");
+            child.wait_with_output()
+        })
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: 'seven' is not a valid day\n"
+    );
+}
@@ -0,0 +1,25 @@
+use advent_of_code_2024::{find_puzzle, AnswerSheet, Part};
+
+fn check_fixture(day: u8, input: &str, expected: &str) {
+    let puzzle = find_puzzle(day).unwrap();
+    let expected = AnswerSheet::parse_day(day, expected).unwrap();
+    let parts: Vec<Part> = Part::all()
+        .into_iter()
+        .filter(|part| expected.get(day, *part).is_some())
+        .collect();
+
+    assert!(!parts.is_empty(), "fixture has no expected answers");
+
+    let run = puzzle.run(input, &parts).unwrap();
+
+    for solution in run.solutions {
+        assert_eq!(
+            Some(&solution.answer),
+            expected.get(day, solution.part),
+            "part {}",
+            solution.part
+        );
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
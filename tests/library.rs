@@ -0,0 +1,63 @@
+use advent_of_code_2024::{
+    day_05::CrateArrangement,
+    day_08::Map2d,
+    day_09::{parse_steps, Rope},
+    day_10::{Instruction, Program},
+    find_puzzle, registry, Answer, Part,
+};
+
+#[test]
+fn lists_registered_days() {
+    let days: Vec<u8> = registry().iter().map(|puzzle| puzzle.day()).collect();
+
+    assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(days
+        .iter()
+        .all(|day| find_puzzle(*day).map(|puzzle| puzzle.day()) == Some(*day)));
+    assert!(days.starts_with(&[1, 2, 3]));
+}
+
+#[test]
+fn solves_day_through_registry() {
+    let puzzle = find_puzzle(1).unwrap();
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+
+    let run = puzzle.run(input, &Part::all()).unwrap();
+
+    assert_eq!(
+        run.answers(),
+        vec![
+            (Part::One, Answer::Number(24000)),
+            (Part::Two, Answer::Number(45000)),
+        ]
+    );
+}
+
+#[test]
+fn reports_parse_errors_through_registry() {
+    let puzzle = find_puzzle(2).unwrap();
+
+    let error = puzzle.run("A Y\nB W", &[Part::One]).unwrap_err();
+
+    assert_eq!((error.line, error.column), (2, 3));
+}
+
+#[test]
+fn uses_model_types_directly() {
+    let mut arrangement: CrateArrangement = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 "
+        .parse()
+        .unwrap();
+    arrangement.move_boxes(2, 1, 1);
+    assert_eq!(arrangement.top_boxes(), "DCP");
+
+    let map: Map2d = "30373\n25512\n65332\n33549\n35390".parse().unwrap();
+    assert_eq!(map.count_visible_trees(), 21);
+
+    let mut rope = Rope::new(2);
+    rope.move_steps(&parse_steps("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").unwrap());
+    assert_eq!(rope.tail_visits().len(), 13);
+
+    let instructions: Vec<Instruction> = vec!["noop".parse().unwrap(), "addx 3".parse().unwrap()];
+    let mut program = Program::new();
+    assert_eq!(program.record_execution(&instructions, &[2]), vec![1]);
+}
@@ -0,0 +1,82 @@
+use advent_of_code_2024::{find_puzzle, registry, Part};
+
+const SEEDS: u64 = 8;
+const SIZES: [usize; 5] = [0, 1, 2, 10, 100];
+
+fn generate(day: u8, seed: u64, size: usize) -> String {
+    find_puzzle(day)
+        .and_then(|puzzle| puzzle.generate(seed, size))
+        .unwrap_or_else(|| panic!("day {day} has no input generator"))
+}
+
+fn stress(day: u8) {
+    let puzzle = find_puzzle(day).unwrap();
+
+    for size in SIZES {
+        for seed in 0..SEEDS {
+            let input = generate(day, seed, size);
+
+            assert_eq!(input, generate(day, seed, size), "seed {seed}, size {size}");
+            puzzle
+                .run(&input, &Part::all())
+                .unwrap_or_else(|error| panic!("seed {seed}, size {size}: {error}\n{input}"));
+        }
+    }
+}
+
+#[test]
+fn generates_inputs_for_every_day() {
+    for puzzle in registry() {
+        assert!(puzzle.generate(0, 10).is_some(), "day {}", puzzle.day());
+    }
+}
+
+#[test]
+fn stresses_day_01() {
+    stress(1);
+}
+
+#[test]
+fn stresses_day_02() {
+    stress(2);
+}
+
+#[test]
+fn stresses_day_03() {
+    stress(3);
+}
+
+#[test]
+fn stresses_day_04() {
+    stress(4);
+}
+
+#[test]
+fn stresses_day_05() {
+    stress(5);
+}
+
+#[test]
+fn stresses_day_06() {
+    stress(6);
+}
+
+#[test]
+fn stresses_day_07() {
+    stress(7);
+}
+
+#[test]
+fn stresses_day_08() {
+    stress(8);
+}
+
+#[test]
+fn stresses_day_09() {
+    stress(9);
+}
+
+#[test]
+fn stresses_day_10() {
+    stress(10);
+}